@@ -0,0 +1,165 @@
+//! The module with the drawer that writes plain text.
+
+use crate::drawer::{self, Drawer};
+use crate::embedder::Embedding;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+///
+/// The `AsciiDrawer` type writes the embedding as plain text, e.g. for logs, terminals or the
+/// documentation of a command line tool. The logical coordinates of the embedding are used as
+/// columns, each level takes as many lines as its highest text, followed by three lines with
+/// the edges to the next level. The edges to children further down, e.g. to the aligned leaves
+/// of a dendrogram, are extended to the children's level.
+///
+/// ```text
+///    calc
+///      |
+///    +-+--+-+
+///    |    | |
+/// assign  b c
+///    |
+///    |
+///    |
+///    x
+/// ```
+///
+/// The drawer doesn't use the layout options, emphasized nodes and the statuses of a diff are
+/// not marked.
+///
+#[derive(Debug, Clone, Default)]
+pub struct AsciiDrawer;
+
+impl AsciiDrawer {
+    /// Method to create a fresh instance of the `AsciiDrawer` type.
+    pub fn new() -> Self {
+        Self
+    }
+
+    ///
+    /// Renders the embedding into lines of text without trailing spaces.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n + w·h) for a drawing of the width w and
+    /// the height h.
+    ///
+    pub fn render(&self, embedding: &Embedding) -> Vec<String> {
+        let mut canvas = Canvas::default();
+        let mut row = 0;
+        if !embedding.captions().is_empty() {
+            for caption in embedding.captions() {
+                canvas.write(row, start(caption.x_center, &caption.text), &caption.text);
+            }
+            row += 2;
+        }
+        // The number of rows of each level's highest text, followed by the connectors
+        let heights = (0..embedding.depth())
+            .map(|level| {
+                embedding
+                    .level(level)
+                    .map(|item| item.text.split('\n').count())
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect::<Vec<usize>>();
+        // The first row of each level's texts
+        let mut level_rows = Vec::with_capacity(embedding.depth());
+        for (level, height) in heights.iter().enumerate() {
+            level_rows.push(row);
+            row += height;
+            if embedding
+                .level(level)
+                .any(|item| embedding.children(item.ord).next().is_some())
+            {
+                row += 3;
+            }
+        }
+
+        for item in embedding.iter() {
+            for (i, line) in item.text.split('\n').enumerate() {
+                canvas.write(
+                    level_rows[item.y_order] + i,
+                    start(item.x_center, line),
+                    line,
+                );
+            }
+            let children = embedding.children(item.ord).collect::<Vec<_>>();
+            if children.is_empty() {
+                continue;
+            }
+            // The connectors follow the highest text of the parent's level
+            let row = level_rows[item.y_order] + heights[item.y_order];
+            let left = std::cmp::min(item.x_center, children[0].x_center);
+            let right = std::cmp::max(item.x_center, children[children.len() - 1].x_center);
+            canvas.put(row, item.x_center, '|');
+            for column in left..=right {
+                canvas.put(row + 1, column, '-');
+            }
+            canvas.put(row + 1, item.x_center, '+');
+            for child in &children {
+                canvas.put(row + 1, child.x_center, '+');
+                // Children further down, e.g. aligned leaves, are connected by a longer line
+                for child_row in row + 2..level_rows[child.y_order] {
+                    canvas.put(child_row, child.x_center, '|');
+                }
+            }
+            if left == right {
+                canvas.put(row + 1, left, '|');
+            }
+        }
+        canvas.into_lines()
+    }
+}
+
+impl Drawer for AsciiDrawer {
+    ///
+    /// Writes the rendered lines of the embedding.
+    ///
+    fn draw(&self, file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        let mut out = BufWriter::new(File::create(file_name)?);
+        for line in self.render(embedding) {
+            writeln!(out, "{}", line)?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// The column of the first character of a text centered at the given column.
+fn start(center: usize, text: &str) -> usize {
+    center.saturating_sub(text.chars().count() / 2)
+}
+
+/// The lines of characters that grow as they are written to.
+#[derive(Default)]
+struct Canvas {
+    lines: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn put(&mut self, row: usize, column: usize, c: char) {
+        if self.lines.len() <= row {
+            self.lines.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.lines[row];
+        if line.len() <= column {
+            line.resize(column + 1, ' ');
+        }
+        line[column] = c;
+    }
+
+    fn write(&mut self, row: usize, column: usize, text: &str) {
+        for (i, c) in text.chars().enumerate() {
+            self.put(row, column + i, c);
+        }
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        self.lines
+            .into_iter()
+            .map(|line| line.into_iter().collect::<String>().trim_end().to_string())
+            .collect()
+    }
+}
@@ -0,0 +1,259 @@
+//! The module that compares two trees and embeds their differences.
+
+use crate::embedder::{Embedder, Embedding, ForestTree};
+use crate::visualize::Visualize;
+use id_tree::{NodeId, Tree};
+#[cfg(feature = "serde_support")]
+use serde_derive::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+///
+/// The DiffStatus type describes how a node changed between two versions of a tree.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+#[cfg_attr(feature = "serde_support", serde(rename_all = "lowercase"))]
+pub enum DiffStatus {
+    /// The node exists in both trees at the corresponding position
+    Unchanged,
+    /// The node only exists in the new tree
+    Inserted,
+    /// The node only exists in the old tree
+    Deleted,
+    /// The node exists in both trees but at different positions
+    Moved,
+    /// The node exists in both trees at the corresponding position but its text changed
+    Relabelled,
+}
+
+///
+/// Compares the old and the new version of a tree and returns the status of each node of both
+/// trees, keyed by the nodes' ids.
+///
+/// Two nodes are considered to be the same node if `eq` returns true for their data. The trees
+/// are matched top-down, the children of matched nodes are matched by their longest common
+/// subsequence. Remaining nodes are matched greedily in pre-order, they are reported as `Moved`
+/// whereas their matched descendants are not. Matched nodes whose texts created by the
+/// `Visualize` trait differ are reported as `Relabelled`, unless they are `Moved`.
+///
+/// # Complexity
+///
+/// The algorithm is of time complexity class O(n²) in the worst case.
+///
+pub fn diff_trees<T, F>(old: &Tree<T>, new: &Tree<T>, eq: F) -> HashMap<NodeId, DiffStatus>
+where
+    T: Visualize,
+    F: Fn(&T, &T) -> bool,
+{
+    let is_eq = |o: &NodeId, n: &NodeId| eq(old.get(o).unwrap().data(), new.get(n).unwrap().data());
+
+    let mut matching = Matching::default();
+    let mut moved: HashSet<NodeId> = HashSet::new();
+
+    if let (Some(old_root), Some(new_root)) = (old.root_node_id(), new.root_node_id()) {
+        if is_eq(old_root, new_root) {
+            matching.add(old_root.clone(), new_root.clone());
+        }
+    }
+
+    let old_pre_order = old
+        .root_node_id()
+        .map(|root| {
+            old.traverse_pre_order_ids(root)
+                .unwrap()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let new_pre_order = new
+        .root_node_id()
+        .map(|root| {
+            new.traverse_pre_order_ids(root)
+                .unwrap()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut old_nodes = old_pre_order.iter();
+    loop {
+        // Match the children of matched nodes
+        while let Some((o, n)) = matching.queue.pop_front() {
+            let old_children = old
+                .children_ids(&o)
+                .unwrap()
+                .filter(|c| !matching.matches.contains_key(*c))
+                .cloned()
+                .collect::<Vec<_>>();
+            let new_children = new
+                .children_ids(&n)
+                .unwrap()
+                .filter(|c| !matching.matched_new.contains(*c))
+                .cloned()
+                .collect::<Vec<_>>();
+            for (oc, nc) in longest_common_subsequence(&old_children, &new_children, &is_eq) {
+                matching.add(oc, nc);
+            }
+        }
+
+        // Greedily match the next remaining node of the old tree
+        let next = old_nodes.by_ref().find_map(|o| {
+            if matching.matches.contains_key(o) {
+                return None;
+            }
+            new_pre_order
+                .iter()
+                .find(|n| !matching.matched_new.contains(*n) && is_eq(o, n))
+                .map(|n| (o.clone(), n.clone()))
+        });
+        match next {
+            Some((o, n)) => {
+                moved.insert(o.clone());
+                matching.add(o, n);
+            }
+            None => break,
+        }
+    }
+
+    let mut statuses = HashMap::new();
+    for o in &old_pre_order {
+        let status = match matching.matches.get(o) {
+            None => DiffStatus::Deleted,
+            Some(_) if moved.contains(o) => DiffStatus::Moved,
+            Some(n) => {
+                if old.get(o).unwrap().data().visualize() != new.get(n).unwrap().data().visualize()
+                {
+                    DiffStatus::Relabelled
+                } else {
+                    DiffStatus::Unchanged
+                }
+            }
+        };
+        if let Some(n) = matching.matches.get(o) {
+            statuses.insert(n.clone(), status);
+        }
+        statuses.insert(o.clone(), status);
+    }
+    for n in &new_pre_order {
+        if !matching.matched_new.contains(n) {
+            statuses.insert(n.clone(), DiffStatus::Inserted);
+        }
+    }
+    statuses
+}
+
+///
+/// Internal helper data for the matching of nodes of two trees
+///
+#[derive(Default)]
+struct Matching {
+    /// Maps matched nodes of the old tree to the ones of the new tree
+    matches: HashMap<NodeId, NodeId>,
+    /// The matched nodes of the new tree
+    matched_new: HashSet<NodeId>,
+    /// The matched pairs whose children still need to be matched
+    queue: VecDeque<(NodeId, NodeId)>,
+}
+
+impl Matching {
+    fn add(&mut self, old: NodeId, new: NodeId) {
+        self.matched_new.insert(new.clone());
+        self.matches.insert(old.clone(), new.clone());
+        self.queue.push_back((old, new));
+    }
+}
+
+/// Returns the pairs of the longest common subsequence of both lists.
+fn longest_common_subsequence<F>(old: &[NodeId], new: &[NodeId], is_eq: &F) -> Vec<(NodeId, NodeId)>
+where
+    F: Fn(&NodeId, &NodeId) -> bool,
+{
+    // lengths[i][j] is the length of the LCS of old[i..] and new[j..]
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if is_eq(&old[i], &new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                std::cmp::max(lengths[i + 1][j], lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if is_eq(&old[i], &new[j]) && lengths[i][j] == lengths[i + 1][j + 1] + 1 {
+            pairs.push((old[i].clone(), new[j].clone()));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+impl<T> Embedder<T>
+where
+    T: Visualize,
+{
+    ///
+    /// This method embeds the old and the new version of a tree side by side, captioned with
+    /// "old" and "new". The status of each item as determined by `diff_trees` is returned by
+    /// `Embedding::diff_status`. For items that were merged from unary chains the first status of
+    /// the chain that is not `Unchanged` is used.
+    ///
+    /// ```
+    /// use id_tree_layout::{diff::DiffStatus, Embedder, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(i32);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut old: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let root = old.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    /// old.insert(Node::new(MyNodeData(1)), UnderNode(&root)).unwrap();
+    /// let mut new: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let root = new.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    /// new.insert(Node::new(MyNodeData(2)), UnderNode(&root)).unwrap();
+    ///
+    /// let embedding = Embedder::new().embed_diff(&old, &new, |o, n| o.0 == n.0);
+    /// assert_eq!(Some(DiffStatus::Deleted), embedding.diff_status(0));
+    /// assert_eq!(Some(DiffStatus::Inserted), embedding.diff_status(2));
+    /// ```
+    ///
+    pub fn embed_diff<F>(&self, old: &Tree<T>, new: &Tree<T>, eq: F) -> Embedding
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let statuses = diff_trees(old, new, eq);
+        let embedding = self
+            .embed_forest(vec![
+                ForestTree::new(old).with_caption("old".to_string()),
+                ForestTree::new(new).with_caption("new".to_string()),
+            ])
+            // The whole trees are embedded, thus there are no invalid node ids.
+            .unwrap();
+        let diff_statuses = embedding
+            .iter()
+            .filter_map(|item| {
+                let chain_statuses = item
+                    .node_id
+                    .iter()
+                    .chain(item.chain.iter())
+                    .filter_map(|n| statuses.get(n).cloned())
+                    .collect::<Vec<DiffStatus>>();
+                chain_statuses
+                    .iter()
+                    .find(|s| **s != DiffStatus::Unchanged)
+                    .or_else(|| chain_statuses.first())
+                    .map(|status| (item.ord, *status))
+            })
+            .collect::<HashMap<usize, DiffStatus>>();
+        embedding.with_diff_statuses(diff_statuses)
+    }
+}
@@ -0,0 +1,165 @@
+//! The module with the drawer that writes the Graphviz DOT format.
+
+use crate::diff::DiffStatus;
+use crate::drawer::{self, Drawer};
+use crate::embedder::{Embedding, PlacedTreeItem};
+use crate::theme::Theme;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+///
+/// The `DotDrawer` type writes the embedding as a directed graph in the DOT language of
+/// Graphviz, e.g. to lay it out again with `dot` or to process it with other graph tools.
+///
+/// The nodes are named `n` followed by their `ord` and written in pre-order, the graph keeps the
+/// order of the children. Emphasized nodes use a bold font, tooltips and URLs of the nodes are
+/// written as the attributes `tooltip` and `URL`, and the statuses of a diff as font colors
+/// of the theme. Each tree of a forest is written as a cluster labelled with its caption.
+///
+/// ```
+/// use id_tree_layout::{DotDrawer, Drawer, Embedder, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+/// tree.insert(Node::new(MyNodeData(1)), UnderNode(&root)).unwrap();
+///
+/// let file_name = std::env::temp_dir().join("tree.dot");
+/// DotDrawer::new().draw(&file_name, &Embedder::embed(&tree)).unwrap();
+/// assert!(std::fs::read_to_string(&file_name).unwrap().contains("n1 -> n0;"));
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct DotDrawer {
+    theme: Theme,
+}
+
+impl DotDrawer {
+    /// Method to create a fresh instance of the `DotDrawer` type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the theme whose colors are used for the texts, the edges and the statuses of a diff.
+    ///
+    pub fn with_theme(self, theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    /// Writes the tree starting at the given root in pre-order.
+    fn write_tree<W: Write>(
+        &self,
+        out: &mut W,
+        embedding: &Embedding,
+        root: usize,
+    ) -> drawer::Result {
+        let mut stack = vec![root];
+        while let Some(ord) = stack.pop() {
+            let item = &embedding[ord];
+            self.write_node(out, item, embedding.diff_status(ord))?;
+            if let Some(parent) = item.parent {
+                writeln!(out, "    n{} -> n{};", parent, ord)?;
+            }
+            let first_child = stack.len();
+            stack.extend(embedding.children(ord).map(|child| child.ord));
+            stack[first_child..].reverse();
+        }
+        Ok(())
+    }
+
+    /// Writes the statement of one node with its attributes.
+    fn write_node<W: Write>(
+        &self,
+        out: &mut W,
+        item: &PlacedTreeItem,
+        diff_status: Option<DiffStatus>,
+    ) -> drawer::Result {
+        let mut attributes = vec![format!("label={}", quote(&item.text))];
+        if item.is_emphasized {
+            attributes.push("fontname=\"Courier-Bold\"".to_string());
+        }
+        let color = match diff_status {
+            Some(DiffStatus::Inserted) => Some(&self.theme.inserted),
+            Some(DiffStatus::Deleted) => Some(&self.theme.deleted),
+            Some(DiffStatus::Moved) => Some(&self.theme.moved),
+            Some(DiffStatus::Relabelled) => Some(&self.theme.relabelled),
+            Some(DiffStatus::Unchanged) | None => None,
+        };
+        if let Some(color) = color {
+            attributes.push(format!("fontcolor={}", quote(color)));
+        }
+        if let Some(tooltip) = &item.tooltip {
+            attributes.push(format!("tooltip={}", quote(tooltip)));
+        }
+        if let Some(url) = &item.url {
+            attributes.push(format!("URL={}", quote(url)));
+        }
+        writeln!(out, "    n{} [{}];", item.ord, attributes.join(", "))?;
+        Ok(())
+    }
+}
+
+impl Drawer for DotDrawer {
+    ///
+    /// Writes the embedding in the DOT language.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    fn draw(&self, file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        let mut out = BufWriter::new(File::create(file_name)?);
+        writeln!(out, "digraph tree {{")?;
+        writeln!(out, "    ordering=out;")?;
+        writeln!(out, "    bgcolor={};", quote(&self.theme.background))?;
+        writeln!(
+            out,
+            "    node [shape=plaintext, fontname=\"Courier\", fontcolor={}];",
+            quote(&self.theme.text)
+        )?;
+        writeln!(
+            out,
+            "    edge [arrowhead=none, color={}];",
+            quote(&self.theme.line)
+        )?;
+        for (index, root) in embedding.roots().enumerate() {
+            match embedding.caption_of(root.ord) {
+                Some(caption) => {
+                    writeln!(out, "    subgraph cluster_{} {{", index)?;
+                    writeln!(out, "    label={};", quote(&caption.text))?;
+                    self.write_tree(&mut out, embedding, root.ord)?;
+                    writeln!(out, "    }}")?;
+                }
+                None => self.write_tree(&mut out, embedding, root.ord)?,
+            }
+        }
+        writeln!(out, "}}")?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Quotes a string as a DOT identifier. Line breaks become centered line breaks of labels.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
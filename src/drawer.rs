@@ -1,15 +1,73 @@
 //! The module with the `Drawer` trait.
-use crate::embedder::PlacedTreeItem;
+use crate::embedder::Embedding;
+use std::fmt;
 
 ///
-/// The `Drawer`'s result type is the `std::io::Result` with `Unit` as success type.
+/// The DrawerError type is the error type of the `Drawer` trait. Drawers that fail for other
+/// reasons than I/O, e.g. because of an unsupported character or a canvas that is too large,
+/// return their own error boxed in the `Other` variant.
 ///
-pub type Result = std::io::Result<()>;
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DrawerError {
+    /// Writing the output failed
+    Io(std::io::Error),
+    /// The drawer failed for a reason specific to it
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl DrawerError {
+    ///
+    /// Creates a drawer specific error from any error type or message.
+    ///
+    /// ```
+    /// use id_tree_layout::drawer::DrawerError;
+    ///
+    /// let error = DrawerError::other("Canvas too large");
+    /// assert_eq!("Canvas too large", error.to_string());
+    /// ```
+    ///
+    pub fn other<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        DrawerError::Other(error.into())
+    }
+}
+
+impl fmt::Display for DrawerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawerError::Io(err) => write!(f, "{}", err),
+            DrawerError::Other(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DrawerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DrawerError::Io(err) => Some(err),
+            DrawerError::Other(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+impl From<std::io::Error> for DrawerError {
+    fn from(io_error: std::io::Error) -> Self {
+        DrawerError::Io(io_error)
+    }
+}
+
+///
+/// The `Drawer`'s result type with `Unit` as success type.
+///
+pub type Result = std::result::Result<(), DrawerError>;
 
 ///
 /// By implementing this trait anyone can provide his own drawer, for instance one that draws onto
 /// a bitmap, if he don't want to use the `SvgDrawer` used by the crate by default.
 ///
 pub trait Drawer {
-    fn draw(&self, file_name: &std::path::Path, embedding: &[PlacedTreeItem]) -> Result;
+    fn draw(&self, file_name: &std::path::Path, embedding: &Embedding) -> Result;
 }
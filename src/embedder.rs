@@ -1,21 +1,494 @@
 //! The module that holds types to embed nodes of a tree into the plane.
 
+use crate::diff::DiffStatus;
+use crate::embedding_error::EmbeddingError;
+use crate::layout_options::{Alignment, LayoutOptions};
+use crate::spatial_index::SpatialIndex;
 use crate::visualize::Visualize;
-use id_tree::{NodeId, Tree};
-use std::collections::{BTreeMap, HashMap};
+use crate::weights::NodeValue;
+use id_tree::{NodeId, NodeIdError, Tree};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde_support")]
+use serde_derive::Serialize;
+use std::collections::HashMap;
+use std::convert::TryFrom;
 
 ///
 /// The Embedding is the interface to drawers that need the embedding
 /// to transform it to their own format.
 ///
-pub type Embedding = Vec<PlacedTreeItem>;
+/// It dereferences to the slice of all `PlacedTreeItem`s ordered by their `ord`, i.e. the item
+/// with the `ord` *n* is found at index *n*. Additionally it provides navigation helpers that
+/// all have constant time complexity, except for the ones that iterate over items.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Embedding {
+    /// The items ordered by `ord`
+    items: Vec<PlacedTreeItem>,
+    /// The children's `ord`s from left to right, indexed by the parent's `ord`
+    children: Vec<Vec<usize>>,
+    /// The `ord`s of the items of each level from left to right, indexed by `y_order`
+    levels: Vec<Vec<usize>>,
+    /// The number of levels from an item down to the deepest item of its subtree, indexed by `ord`
+    heights: Vec<usize>,
+    /// The left-most logical x coordinate of the slot of each item's subtree, indexed by `ord`
+    starts: Vec<usize>,
+    /// The captions of the trees of a forest
+    captions: Vec<Caption>,
+    /// The items' statuses if the embedding shows the differences between two trees, keyed by
+    /// `ord`
+    diff_statuses: HashMap<usize, DiffStatus>,
+}
+
+///
+/// The Caption is the embedding information for the caption of one tree of a forest.
+/// Captions are placed above the first level, horizontally centered over their trees.
+///
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct Caption {
+    /// The logical x coordinate of the caption's center
+    pub x_center: usize,
+    /// The x-extent of the caption's text in logical coordinate units
+    pub x_extent: usize,
+    /// The caption's text
+    pub text: String,
+}
+
+///
+/// An axis-aligned rectangle in logical coordinate units of the embedding.
+/// The y-axis is measured in levels.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BoundingBox {
+    /// The left-most logical x coordinate
+    pub x: usize,
+    /// The top-most level
+    pub y: usize,
+    /// The extent in logical x coordinate units
+    pub width: usize,
+    /// The number of levels
+    pub height: usize,
+}
+
+impl Embedding {
+    ///
+    /// Returns the root item, if the embedding is not empty.
+    /// For the embedding of a forest this is the root of the right-most tree.
+    ///
+    pub fn root(&self) -> Option<&PlacedTreeItem> {
+        self.items.iter().rev().find(|item| item.parent.is_none())
+    }
+
+    ///
+    /// Returns the root items from left to right.
+    /// There is more than one root only in the embedding of a forest.
+    ///
+    pub fn roots(&self) -> impl Iterator<Item = &PlacedTreeItem> + '_ {
+        self.items.iter().filter(|item| item.parent.is_none())
+    }
+
+    ///
+    /// Returns the captions of the trees of a forest from left to right.
+    ///
+    pub fn captions(&self) -> &[Caption] {
+        &self.captions
+    }
+
+    ///
+    /// Returns the caption placed over the tree with the given root, if there is one.
+    ///
+    pub fn caption_of(&self, root: usize) -> Option<&Caption> {
+        let bounding_box = self.subtree_bounding_box(root);
+        self.captions.iter().find(|caption| {
+            bounding_box.x <= caption.x_center
+                && caption.x_center < bounding_box.x + bounding_box.width
+        })
+    }
+
+    ///
+    /// Returns the parent item of the item with the given `ord`.
+    ///
+    pub fn parent(&self, ord: usize) -> Option<&PlacedTreeItem> {
+        self.items
+            .get(ord)
+            .and_then(|item| item.parent)
+            .map(|parent| &self.items[parent])
+    }
+
+    ///
+    /// Returns the children of the item with the given `ord` from left to right.
+    ///
+    pub fn children(&self, ord: usize) -> impl Iterator<Item = &PlacedTreeItem> + '_ {
+        self.children
+            .get(ord)
+            .into_iter()
+            .flatten()
+            .map(move |child| &self.items[*child])
+    }
+
+    ///
+    /// Returns the number of levels, i.e. the depth of the deepest item plus one.
+    ///
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    ///
+    /// Returns the items of the given level from left to right.
+    ///
+    pub fn level(&self, y_order: usize) -> impl Iterator<Item = &PlacedTreeItem> + '_ {
+        self.levels
+            .get(y_order)
+            .into_iter()
+            .flatten()
+            .map(move |ord| &self.items[*ord])
+    }
+
+    ///
+    /// Returns the bounding box of the whole embedding.
+    /// For the embedding of a forest its horizontal extent includes the trees' captions.
+    ///
+    pub fn bounding_box(&self) -> BoundingBox {
+        let boxes = self
+            .roots()
+            .map(|root| self.subtree_bounding_box(root.ord))
+            .chain(self.captions.iter().map(|caption| BoundingBox {
+                x: caption.x_center.saturating_sub(caption.x_extent / 2),
+                y: 0,
+                width: caption.x_extent,
+                height: 0,
+            }));
+        boxes
+            .reduce(|b1, b2| {
+                let x = std::cmp::min(b1.x, b2.x);
+                let y = std::cmp::min(b1.y, b2.y);
+                BoundingBox {
+                    x,
+                    y,
+                    width: std::cmp::max(b1.x + b1.width, b2.x + b2.width) - x,
+                    height: std::cmp::max(b1.y + b1.height, b2.y + b2.height) - y,
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the bounding box of the subtree starting at the item with the given `ord`.
+    /// The horizontal extent is the space the embedder reserved for the subtree, it includes the
+    /// texts of all items of the subtree.
+    ///
+    pub fn subtree_bounding_box(&self, ord: usize) -> BoundingBox {
+        self.items
+            .get(ord)
+            .map(|item| BoundingBox {
+                x: self.starts[ord],
+                y: item.y_order,
+                width: item.x_extent_children,
+                height: self.heights[ord],
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the status of the item with the given `ord` if the embedding shows the
+    /// differences between two trees, see `Embedder::embed_diff`.
+    ///
+    pub fn diff_status(&self, ord: usize) -> Option<DiffStatus> {
+        self.diff_statuses.get(&ord).cloned()
+    }
+
+    /// Sets the statuses of the items, keyed by `ord`.
+    pub(crate) fn with_diff_statuses(self, diff_statuses: HashMap<usize, DiffStatus>) -> Self {
+        Self {
+            diff_statuses,
+            ..self
+        }
+    }
+
+    ///
+    /// Creates an index for hit testing in logical coordinates.
+    ///
+    pub fn spatial_index(&self) -> SpatialIndex {
+        SpatialIndex::from_embedding(self)
+    }
+}
+
+///
+/// Creates an embedding from a list of items, for instance to feed a drawer with items from other
+/// sources than the `Embedder`.
+///
+/// # Errors
+///
+/// The items' `ord`s need to be the numbers from zero to the number of items minus one and the
+/// `parent` of each item needs to refer to an item with a greater `ord`, i.e. the `ord`s need to
+/// reflect a post-ordering of the nodes. The text of each item needs to start at a logical x
+/// coordinate of at least zero, i.e. its `x_center` needs to be at least half its `x_extent`.
+///
+/// ```
+/// use id_tree_layout::{Embedding, EmbeddingError, PlacedTreeItem};
+/// use std::convert::TryFrom;
+///
+/// let mut item = PlacedTreeItem::default();
+/// item.x_extent = 4;
+/// item.x_center = 2;
+/// assert!(Embedding::try_from(vec![item.clone()]).is_ok());
+///
+/// item.x_center = 1;
+/// assert_eq!(EmbeddingError::InvalidExtent(0), Embedding::try_from(vec![item]).unwrap_err());
+/// ```
+///
+impl TryFrom<Vec<PlacedTreeItem>> for Embedding {
+    type Error = EmbeddingError;
+
+    fn try_from(mut items: Vec<PlacedTreeItem>) -> Result<Self, Self::Error> {
+        items.sort_by_key(|item| item.ord);
+        for (index, item) in items.iter().enumerate() {
+            if index != item.ord {
+                return Err(EmbeddingError::InvalidOrd(item.ord));
+            }
+            match item.parent {
+                Some(parent) if parent <= item.ord || parent >= items.len() => {
+                    return Err(EmbeddingError::InvalidParent {
+                        ord: item.ord,
+                        parent,
+                    });
+                }
+                _ => {}
+            }
+            if item.x_center < item.x_extent / 2 {
+                return Err(EmbeddingError::InvalidExtent(item.ord));
+            }
+        }
+        Ok(Self::from_valid_items(items))
+    }
+}
+
+impl Embedding {
+    ///
+    /// Creates an embedding from items that fulfill the requirements checked by `try_from`, as
+    /// the embedders create them.
+    ///
+    pub(crate) fn from_valid_items(mut items: Vec<PlacedTreeItem>) -> Self {
+        items.sort_by_key(|item| item.ord);
+        let mut children = vec![Vec::new(); items.len()];
+        let mut levels: Vec<Vec<usize>> = Vec::new();
+        // The level below the deepest item of each subtree
+        let mut bottoms = items
+            .iter()
+            .map(|item| item.y_order + 1)
+            .collect::<Vec<usize>>();
+        // The slot of a subtree starts either at its root's text or at its first child's slot,
+        // whatever the alignment is
+        let mut starts = items
+            .iter()
+            .map(|item| item.x_center.saturating_sub(item.x_extent / 2))
+            .collect::<Vec<usize>>();
+        for item in &items {
+            if let Some(parent) = item.parent {
+                children[parent].push(item.ord);
+                bottoms[parent] = std::cmp::max(bottoms[parent], bottoms[item.ord]);
+                starts[parent] = std::cmp::min(starts[parent], starts[item.ord]);
+            }
+            if levels.len() <= item.y_order {
+                levels.resize(item.y_order + 1, Vec::new());
+            }
+            levels[item.y_order].push(item.ord);
+        }
+        let heights = items
+            .iter()
+            .map(|item| bottoms[item.ord] - item.y_order)
+            .collect();
+        Self {
+            items,
+            children,
+            levels,
+            heights,
+            starts,
+            captions: Vec::new(),
+            diff_statuses: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Replaces the items from some `ord` on by the given segments, which follow each other in
+    /// the order of their new `ord`s starting at the first segment's one. Leading segments that
+    /// leave their subtree unchanged are skipped, the items before the first changed segment
+    /// are kept in place. Returns the `ord` of the first replaced item and the replaced items
+    /// that are not part of a `Segment::Subtree`.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(s + d) where s is the number of replaced
+    /// items and d the number of levels.
+    ///
+    pub(crate) fn splice(&mut self, segments: Vec<Segment>) -> (usize, Vec<PlacedTreeItem>) {
+        let mut segments = segments.into_iter().peekable();
+        let mut start = 0;
+        while let Some(Segment::Subtree {
+            root,
+            ord,
+            x_center,
+            y_order,
+            ..
+        }) = segments.peek()
+        {
+            let item = &self.items[*root];
+            if root != ord || item.x_center != *x_center || item.y_order != *y_order {
+                break;
+            }
+            start = ord + 1;
+            segments.next();
+        }
+
+        let mut old_items = self
+            .items
+            .split_off(start)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<Option<PlacedTreeItem>>>();
+        let mut old_children = self.children.split_off(start);
+        let old_heights = self.heights.split_off(start);
+        let old_starts = self.starts.split_off(start);
+        for level in &mut self.levels {
+            while level.last().is_some_and(|ord| *ord >= start) {
+                level.pop();
+            }
+        }
+
+        let moved = |value: usize, offset: isize| (value as isize + offset) as usize;
+        for segment in segments {
+            match segment {
+                Segment::Subtree {
+                    root,
+                    size,
+                    ord,
+                    x_center,
+                    y_order,
+                } => {
+                    let old_root = old_items[root - start].as_ref().unwrap();
+                    let ord_offset = ord as isize - root as isize;
+                    let x_offset = x_center as isize - old_root.x_center as isize;
+                    let y_offset = y_order as isize - old_root.y_order as isize;
+                    for old in root + 1 - size..=root {
+                        let mut item = old_items[old - start].take().unwrap();
+                        item.ord = moved(item.ord, ord_offset);
+                        // The parent of the subtree's root is set by its own segment
+                        item.parent = item
+                            .parent
+                            .filter(|_| old != root)
+                            .map(|parent| moved(parent, ord_offset));
+                        item.x_center = moved(item.x_center, x_offset);
+                        item.y_order = moved(item.y_order, y_offset);
+                        let mut children = std::mem::take(&mut old_children[old - start]);
+                        for child in &mut children {
+                            *child = moved(*child, ord_offset);
+                        }
+                        let subtree_start = moved(old_starts[old - start], x_offset);
+                        self.push(item, children, old_heights[old - start], subtree_start);
+                    }
+                }
+                Segment::Item(item, children) => {
+                    let mut bottom = item.y_order + 1;
+                    let mut subtree_start = item.x_center.saturating_sub(item.x_extent / 2);
+                    for child in &children {
+                        let child_item = &mut self.items[*child];
+                        child_item.parent = Some(item.ord);
+                        bottom = std::cmp::max(bottom, child_item.y_order + self.heights[*child]);
+                        subtree_start = std::cmp::min(subtree_start, self.starts[*child]);
+                    }
+                    let height = bottom - item.y_order;
+                    self.push(*item, children, height, subtree_start);
+                }
+            }
+        }
+        while self.levels.last().is_some_and(Vec::is_empty) {
+            self.levels.pop();
+        }
+
+        (start, old_items.into_iter().flatten().collect())
+    }
+
+    /// Appends an item, whose `ord` needs to be the number of items, with its derived data.
+    fn push(
+        &mut self,
+        item: PlacedTreeItem,
+        children: Vec<usize>,
+        height: usize,
+        subtree_start: usize,
+    ) {
+        debug_assert_eq!(self.items.len(), item.ord);
+        if self.levels.len() <= item.y_order {
+            self.levels.resize(item.y_order + 1, Vec::new());
+        }
+        self.levels[item.y_order].push(item.ord);
+        self.items.push(item);
+        self.children.push(children);
+        self.heights.push(height);
+        self.starts.push(subtree_start);
+    }
+}
+
+///
+/// Internal type of the parts that replace the items of an `Embedding` from some `ord` on, see
+/// `Embedding::splice`.
+///
+pub(crate) enum Segment {
+    /// The unchanged subtree of `size` items whose root had the `ord` `root`, moved to the given
+    /// `ord` and position of its root
+    Subtree {
+        root: usize,
+        size: usize,
+        ord: usize,
+        x_center: usize,
+        y_order: usize,
+    },
+    /// An item with the `ord`s of its children, which precede it
+    Item(Box<PlacedTreeItem>, Vec<usize>),
+}
+
+impl std::ops::Deref for Embedding {
+    type Target = [PlacedTreeItem];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<'a> IntoIterator for &'a Embedding {
+    type Item = &'a PlacedTreeItem;
+    type IntoIter = std::slice::Iter<'a, PlacedTreeItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl IntoIterator for Embedding {
+    type Item = PlacedTreeItem;
+    type IntoIter = std::vec::IntoIter<PlacedTreeItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
 
 ///
 /// The PlacedTreeItem is the embedding information for one single tree node.
 /// It is used only in a collection type `Embedding`.
-/// External API: keep stable.
+/// External API: new fields may be added, thus items are created from `Default::default()`
+/// outside of this crate.
 ///
-#[derive(Debug, Clone, Default)]
+/// ```
+/// use id_tree_layout::PlacedTreeItem;
+///
+/// let mut item = PlacedTreeItem::default();
+/// item.text = "root".to_string();
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
 pub struct PlacedTreeItem {
     /// The nodes level, root has level 0. Can be used to calculate an y coordinate for the node
     pub y_order: usize,
@@ -29,10 +502,26 @@ pub struct PlacedTreeItem {
     pub text: String,
     /// The *emphasize* property obtained from the `Visualize` trait
     pub is_emphasized: bool,
+    /// The width hint obtained from the `Visualize` trait, if the node has one
+    pub width_hint: Option<usize>,
+    /// The height hint obtained from the `Visualize` trait, if the node has one
+    pub height_hint: Option<usize>,
+    /// The tooltip obtained from the `Visualize` trait, if the node has one
+    pub tooltip: Option<String>,
+    /// The URL obtained from the `Visualize` trait, if the node has one
+    pub url: Option<String>,
     /// The parent's `ord`, if there is one
     pub parent: Option<usize>,
     /// A unique number reflecting the topological post-ordering of the nodes in the tree
     pub ord: usize,
+    /// The ids of all tree nodes that were merged into this item by the compression of unary
+    /// chains, top-most node first. Empty if the item wasn't merged.
+    pub chain: Vec<NodeId>,
+    /// The id of the tree node the item was created from, for merged items this is the id of the
+    /// top-most node of the chain. Use it to look up the original node data in the tree.
+    /// The Option type is used to circumvent missing Default implementation of `NodeId`s.
+    /// Items created by the `Embedder` always contain Some value.
+    pub node_id: Option<NodeId>,
 }
 
 ///
@@ -47,8 +536,35 @@ impl From<ItemEmbeddingData> for PlacedTreeItem {
             x_extent_children: e.x_extent_children,
             text: e.text,
             is_emphasized: e.is_emphasized,
+            width_hint: e.width_hint,
+            height_hint: e.height_hint,
+            tooltip: e.tooltip,
+            url: e.url,
             parent: e.parent,
             ord: e.ord,
+            chain: e.chain,
+            node_id: e.node_id,
+        }
+    }
+}
+
+///
+/// The ChainCompression type selects how the labels of a unary chain are combined when the chain
+/// is merged into a single node.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainCompression {
+    /// The labels are joined into one line using the given separator
+    Joined(String),
+    /// The labels are stacked, one line per merged node
+    Stacked,
+}
+
+impl ChainCompression {
+    fn combine(&self, labels: &[String]) -> String {
+        match self {
+            ChainCompression::Joined(separator) => labels.join(separator),
+            ChainCompression::Stacked => labels.join("\n"),
         }
     }
 }
@@ -72,65 +588,393 @@ struct ItemEmbeddingData {
     text: String,
     /// The *emphasize* property obtained from the `Visualize` trait
     is_emphasized: bool,
+    /// The width hint obtained from the `Visualize` trait
+    width_hint: Option<usize>,
+    /// The height hint obtained from the `Visualize` trait
+    height_hint: Option<usize>,
+    /// The tooltip obtained from the `Visualize` trait
+    tooltip: Option<String>,
+    /// The URL obtained from the `Visualize` trait
+    url: Option<String>,
     /// The parent's `ord`, if there is one
     parent: Option<usize>,
     /// A unique number reflecting the topological post-ordering of the nodes in the tree
     ord: usize,
+    /// The children's `ord`s from left to right
+    children: Vec<usize>,
+    /// The smallest `ord` within the node's subtree, the subtree's items occupy all `ord`s from
+    /// here up to the node's own one
+    subtree_start: usize,
+    /// The ids of all tree nodes merged into this item, see `PlacedTreeItem::chain`
+    chain: Vec<NodeId>,
     /// Internal node id - The Option type used to circumvent missing Default implementation of `NodeId`s
     /// There should normally be no None values in there.
     node_id: Option<NodeId>,
 }
 
 ///
-/// Internal helper data
+/// Internal helper data, the items are indexed by their `ord`
 ///
-struct EmbeddingHelperData(BTreeMap<usize, ItemEmbeddingData>, HashMap<NodeId, usize>);
+struct EmbeddingHelperData(Vec<ItemEmbeddingData>);
 
 impl EmbeddingHelperData {
     fn new() -> Self {
-        Self(BTreeMap::new(), HashMap::new())
+        Self(Vec::new())
     }
 
     fn get_by_ord(&self, ord: usize) -> Option<&ItemEmbeddingData> {
-        self.0.get(&ord)
+        self.0.get(ord)
     }
 
     fn get_mut_by_ord(&mut self, ord: usize) -> Option<&mut ItemEmbeddingData> {
-        self.0.get_mut(&ord)
+        self.0.get_mut(ord)
+    }
+
+    fn push(&mut self, item: ItemEmbeddingData) {
+        debug_assert_eq!(item.ord, self.0.len());
+        self.0.push(item);
+    }
+}
+
+///
+/// Internal view on one node of the tree that is actually embedded.
+/// The `id_tree::Tree` itself is never touched by any transformation the embedder applies.
+///
+struct VirtualNode {
+    /// The id of the tree node
+    node_id: NodeId,
+    /// Indices of the children in the post-ordered list of virtual nodes
+    children: Vec<usize>,
+    /// Index of the parent in the post-ordered list of virtual nodes
+    parent: Option<usize>,
+}
+
+///
+/// The ForestTree type describes one tree of a forest that is embedded with
+/// `Embedder::embed_forest`.
+///
+pub struct ForestTree<'a, T> {
+    tree: &'a Tree<T>,
+    root: Option<NodeId>,
+    caption: Option<String>,
+}
+
+impl<'a, T> ForestTree<'a, T> {
+    ///
+    /// Creates a new ForestTree for the whole tree.
+    ///
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self {
+            tree,
+            root: None,
+            caption: None,
+        }
     }
 
-    fn get_by_node_id(&self, node_id: &NodeId) -> Option<&ItemEmbeddingData> {
-        self.1.get(node_id).and_then(|n| self.0.get(n))
+    ///
+    /// Restricts the ForestTree to the subtree starting at the given node.
+    ///
+    pub fn with_root(self, root: NodeId) -> Self {
+        Self {
+            root: Some(root),
+            ..self
+        }
     }
 
-    fn get_mut_by_node_id(&mut self, node_id: &NodeId) -> Option<&mut ItemEmbeddingData> {
-        let ord = self.1.get(node_id).cloned();
-        ord.and_then(move |n| self.0.get_mut(&n))
+    ///
+    /// Sets the caption that is placed above the tree.
+    ///
+    pub fn with_caption(self, caption: String) -> Self {
+        Self {
+            caption: Some(caption),
+            ..self
+        }
     }
+}
 
-    fn insert(&mut self, ord: usize, item: ItemEmbeddingData) {
-        item.node_id.as_ref().map(|n| self.1.insert(n.clone(), ord));
-        self.0.insert(ord, item);
+impl<'a, T> From<&'a Tree<T>> for ForestTree<'a, T> {
+    fn from(tree: &'a Tree<T>) -> Self {
+        Self::new(tree)
     }
 }
 
 ///
-/// The Embedder type provides a single public method `embed` to arrange nodes of a tree into the
-/// plane.
+/// Internal type of predicates on the tree's node data
+///
+type NodePredicate<T> = Box<dyn Fn(&T) -> bool>;
+
+///
+/// Internal configuration of the dendrogram mode
+///
+struct Dendrogram<T> {
+    /// The merge height of internal nodes, if they are to be placed by it
+    merge_height: Option<NodeValue<T>>,
+    /// The number of levels the merge heights are spread over
+    levels: usize,
+}
+
+///
+/// The Embedder type arranges nodes of a tree into the plane.
+/// The simplest way to use it is the associated function `embed`. If you want to apply
+/// transformations like the compression of unary chains create a configured instance with the
+/// builder methods and call `embed_tree` on it.
 ///
 pub struct Embedder<T>
 where
     T: Visualize,
 {
+    chain_compression: Option<ChainCompression>,
+    is_hidden: Option<NodePredicate<T>>,
+    dendrogram: Option<Dendrogram<T>>,
+    options: LayoutOptions,
+    #[cfg(feature = "rayon")]
+    parallel_threshold: usize,
     _1: std::marker::PhantomData<T>,
 }
 
+impl<T> Default for Embedder<T>
+where
+    T: Visualize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Embedder<T>
 where
     T: Visualize,
 {
+    ///
+    /// Creates a new Embedder without any transformations applied.
+    ///
+    pub fn new() -> Self {
+        Self {
+            chain_compression: None,
+            is_hidden: None,
+            dendrogram: None,
+            options: LayoutOptions::default(),
+            #[cfg(feature = "rayon")]
+            parallel_threshold: 10_000,
+            _1: std::marker::PhantomData,
+        }
+    }
+
+    ///
+    /// Enables the compression of unary chains.
+    /// A maximal chain of nodes each having exactly one child is merged into one single item
+    /// together with the chain's last node. The labels of the merged nodes are combined as
+    /// selected by the `compression` parameter. A merged item is emphasized if any of the merged
+    /// nodes is.
+    ///
+    /// ```
+    /// use id_tree_layout::{ChainCompression, Embedder, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(&'static str);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let expr = tree.insert(Node::new(MyNodeData("Expr")), AsRoot).unwrap();
+    /// let term = tree.insert(Node::new(MyNodeData("Term")), UnderNode(&expr)).unwrap();
+    /// tree.insert(Node::new(MyNodeData("1")), UnderNode(&term)).unwrap();
+    ///
+    /// let embedding = Embedder::new()
+    ///     .with_chain_compression(ChainCompression::Joined(" > ".to_string()))
+    ///     .embed_tree(&tree);
+    /// assert_eq!(1, embedding.len());
+    /// assert_eq!("Expr > Term > 1", embedding[0].text);
+    /// assert_eq!(3, embedding[0].chain.len());
+    /// ```
+    ///
+    pub fn with_chain_compression(self, compression: ChainCompression) -> Self {
+        Self {
+            chain_compression: Some(compression),
+            ..self
+        }
+    }
+
+    ///
+    /// Hides all nodes for which the given predicate returns true.
+    /// The children of a hidden node are spliced into the child list of the nearest visible
+    /// ancestor at the hidden node's position. The tree itself is not modified, all properties of
+    /// the embedding like `parent` and `y_order` reflect the filtered structure.
+    /// The root node is never hidden.
+    /// Unary chains are compressed after hidden nodes have been removed.
+    ///
+    /// ```
+    /// use id_tree_layout::{Embedder, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(&'static str);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let call = tree.insert(Node::new(MyNodeData("Call")), AsRoot).unwrap();
+    /// let args = tree.insert(Node::new(MyNodeData("(")), UnderNode(&call)).unwrap();
+    /// tree.insert(Node::new(MyNodeData("x")), UnderNode(&args)).unwrap();
+    /// tree.insert(Node::new(MyNodeData("y")), UnderNode(&args)).unwrap();
+    ///
+    /// let embedding = Embedder::new()
+    ///     .with_hidden_nodes(|n: &MyNodeData| n.0 == "(")
+    ///     .embed_tree(&tree);
+    /// assert_eq!(3, embedding.len());
+    /// assert_eq!(Some(2), embedding[0].parent);
+    /// assert_eq!(1, embedding[1].y_order);
+    /// ```
+    ///
+    pub fn with_hidden_nodes<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        Self {
+            is_hidden: Some(Box::new(predicate)),
+            ..self
+        }
+    }
+
+    ///
+    /// Enables the dendrogram mode in which all leaves are placed on the bottom level of the
+    /// embedding regardless of their depth. Internal nodes keep their level.
+    ///
+    /// ```
+    /// use id_tree_layout::{Embedder, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(&'static str);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let root = tree.insert(Node::new(MyNodeData("abc")), AsRoot).unwrap();
+    /// tree.insert(Node::new(MyNodeData("a")), UnderNode(&root)).unwrap();
+    /// let bc = tree.insert(Node::new(MyNodeData("bc")), UnderNode(&root)).unwrap();
+    /// tree.insert(Node::new(MyNodeData("b")), UnderNode(&bc)).unwrap();
+    /// tree.insert(Node::new(MyNodeData("c")), UnderNode(&bc)).unwrap();
+    ///
+    /// let embedding = Embedder::new().with_aligned_leaves().embed_tree(&tree);
+    /// assert_eq!(2, embedding[0].y_order);
+    /// assert_eq!(2, embedding[1].y_order);
+    /// assert_eq!(1, embedding[3].y_order);
+    /// ```
+    ///
+    pub fn with_aligned_leaves(self) -> Self {
+        Self {
+            dendrogram: Some(Dendrogram {
+                merge_height: None,
+                levels: 0,
+            }),
+            ..self
+        }
+    }
+
+    ///
+    /// Enables the dendrogram mode in which all leaves are placed on the bottom level and
+    /// internal nodes are placed according to their merge height, e.g. the merge distance of a
+    /// hierarchical clustering. The function `height` returns the merge height of a node's data,
+    /// it is only called for internal nodes.
+    ///
+    /// The range from zero to the root's merge height r is spread over `levels` levels: the root
+    /// is always placed on level 0, another internal node with merge height h on level
+    /// `round((1 - h / r) * (levels - 1))`, and the leaves on level `levels`. An internal node is
+    /// always placed at least one level below its parent, e.g. if its merge height isn't less
+    /// than its parent's one, and the leaves below all internal nodes, so a greater number of
+    /// levels gives a finer resolution. Negative heights are treated as zero.
+    ///
+    /// ```
+    /// use id_tree_layout::{Embedder, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct Cluster(&'static str, f64);
+    ///
+    /// impl Visualize for Cluster {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<Cluster> = TreeBuilder::new().build();
+    /// let root = tree.insert(Node::new(Cluster("abc", 10.0)), AsRoot).unwrap();
+    /// tree.insert(Node::new(Cluster("a", 0.0)), UnderNode(&root)).unwrap();
+    /// let bc = tree.insert(Node::new(Cluster("bc", 2.0)), UnderNode(&root)).unwrap();
+    /// tree.insert(Node::new(Cluster("b", 0.0)), UnderNode(&bc)).unwrap();
+    /// tree.insert(Node::new(Cluster("c", 0.0)), UnderNode(&bc)).unwrap();
+    ///
+    /// let embedding = Embedder::new()
+    ///     .with_merge_heights(|c: &Cluster| c.1, 11)
+    ///     .embed_tree(&tree);
+    /// assert_eq!(0, embedding[4].y_order);
+    /// assert_eq!(8, embedding[3].y_order);
+    /// assert_eq!(11, embedding[0].y_order);
+    /// ```
+    ///
+    pub fn with_merge_heights<F>(self, height: F, levels: usize) -> Self
+    where
+        F: Fn(&T) -> f64 + 'static,
+    {
+        Self {
+            dendrogram: Some(Dendrogram {
+                merge_height: Some(Box::new(height)),
+                levels,
+            }),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the layout options. The embedder applies the horizontal gaps and the alignment, the
+    /// remaining options are up to the drawer.
+    ///
+    /// ```
+    /// use id_tree_layout::{Embedder, LayoutOptions, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(i32);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    /// tree.insert(Node::new(MyNodeData(1)), UnderNode(&root)).unwrap();
+    /// tree.insert(Node::new(MyNodeData(2)), UnderNode(&root)).unwrap();
+    ///
+    /// let embedding = Embedder::new()
+    ///     .with_options(LayoutOptions {
+    ///         subtree_gap: 3,
+    ///         ..Default::default()
+    ///     })
+    ///     .embed_tree(&tree);
+    /// assert_eq!(7, embedding.bounding_box().width);
+    /// ```
+    ///
+    pub fn with_options(self, options: LayoutOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    ///
+    /// Sets the minimum number of items from which on `par_embed_tree` embeds in parallel.
+    /// Smaller trees are embedded sequentially. The default is 10000.
+    /// The threshold doesn't affect `embed_tree`, which is always sequential.
+    ///
+    #[cfg(feature = "rayon")]
+    pub fn with_parallel_threshold(self, threshold: usize) -> Self {
+        Self {
+            parallel_threshold: threshold,
+            ..self
+        }
+    }
+
     ///
     /// This method creates an embedding of the nodes of the given tree in the plane.
+    /// No transformations are applied.
     ///
     /// # Panics
     ///
@@ -142,170 +986,607 @@ where
     /// The algorithm is of time complexity class O(n).
     ///
     pub fn embed(tree: &Tree<T>) -> Embedding {
+        Self::new().embed_tree(tree)
+    }
+
+    ///
+    /// This method creates an embedding of the nodes of the given tree in the plane and applies
+    /// the transformations configured on this instance.
+    /// The embedding is computed sequentially, see `par_embed_tree` for the parallel
+    /// alternative of the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// The method should not panic. If you encounter a panic this should be originated from
+    /// bugs in coding. Please report such panics.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn embed_tree(&self, tree: &Tree<T>) -> Embedding {
+        self.embed_from(tree, tree.root_node_id())
+    }
+
+    ///
+    /// This method creates an embedding of the nodes of the subtree starting at the given node
+    /// and applies the transformations configured on this instance.
+    /// The `root` is never hidden.
+    ///
+    /// # Errors
+    ///
+    /// The method fails if `root` is not a valid node of the tree.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn embed_subtree(
+        &self,
+        tree: &Tree<T>,
+        root: &NodeId,
+    ) -> std::result::Result<Embedding, NodeIdError> {
+        tree.get(root)?;
+        Ok(self.embed_from(tree, Some(root)))
+    }
+
+    ///
+    /// This method embeds several trees, or subtrees, side by side into one embedding.
+    /// The trees are separated by the `tree_gap` of the layout options. The ords of the items of
+    /// each tree follow the ones of the tree's left neighbour.
+    /// If a tree has a caption it is horizontally centered over the tree. Trees that are
+    /// narrower than their caption are centered under it.
+    ///
+    /// ```
+    /// use id_tree_layout::{Embedder, ForestTree, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    ///
+    /// struct MyNodeData(i32);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree1: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// tree1.insert(Node::new(MyNodeData(1)), AsRoot).unwrap();
+    /// let mut tree2: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// tree2.insert(Node::new(MyNodeData(2)), AsRoot).unwrap();
+    ///
+    /// let embedding = Embedder::new()
+    ///     .embed_forest(vec![
+    ///         ForestTree::new(&tree1).with_caption("first".to_string()),
+    ///         ForestTree::new(&tree2).with_caption("second".to_string()),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(2, embedding.roots().count());
+    /// assert_eq!(2, embedding.captions().len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The method fails if a subtree's root is not a valid node of its tree.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn embed_forest<'a, I>(&self, trees: I) -> std::result::Result<Embedding, NodeIdError>
+    where
+        I: IntoIterator<Item = ForestTree<'a, T>>,
+        T: 'a,
+    {
+        let mut items: Vec<PlacedTreeItem> = Vec::new();
+        let mut captions = Vec::new();
+        let mut x_offset = 0;
+
+        for (i, forest_tree) in trees.into_iter().enumerate() {
+            let embedding = match &forest_tree.root {
+                Some(root) => self.embed_subtree(forest_tree.tree, root)?,
+                None => self.embed_tree(forest_tree.tree),
+            };
+            let tree_width = embedding.bounding_box().width;
+            let caption_extent = forest_tree.caption.as_ref().map_or(0, |caption| {
+                Self::text_extent(caption) + self.options.sibling_gap
+            });
+            let slot_width = std::cmp::max(tree_width, caption_extent);
+            if i > 0 {
+                x_offset += self.options.tree_gap;
+            }
+
+            let tree_offset = x_offset + (slot_width - tree_width) / 2;
+            let ord_offset = items.len();
+            items.extend(embedding.into_iter().map(|mut item| {
+                item.x_center += tree_offset;
+                item.ord += ord_offset;
+                item.parent = item.parent.map(|parent| parent + ord_offset);
+                item
+            }));
+
+            if let Some(text) = forest_tree.caption {
+                captions.push(Caption {
+                    x_center: x_offset + slot_width / 2,
+                    x_extent: caption_extent,
+                    text,
+                });
+            }
+            x_offset += slot_width;
+        }
+
+        let mut embedding = Embedding::from_valid_items(items);
+        embedding.captions = captions;
+        Ok(embedding)
+    }
+
+    fn embed_from(&self, tree: &Tree<T>, root: Option<&NodeId>) -> Embedding {
         // Insert all tree items with their indices
         // After this step each item has following properties set:
-        // 'x_extent', 'text', 'is_emphasized', 'x_extent_children', 'ord'
-        let mut items = Self::create_initial_embedding_data(tree);
-        debug_assert_eq!(items.0.len(), items.1.len());
+        // 'ord', 'parent', 'children', 'subtree_start', 'chain', 'node_id'
+        let items = self.create_initial_embedding_data(tree, root);
+        self.embed_items(tree, items)
+    }
+
+    fn embed_items(&self, tree: &Tree<T>, mut items: EmbeddingHelperData) -> Embedding {
+        // Set the text representation of each item
+        // After this step each item has following additional properties set:
+        // 'text', 'is_emphasized', 'width_hint', 'height_hint', 'x_extent'
+        for item in &mut items.0 {
+            Self::apply_label(
+                tree,
+                self.chain_compression.as_ref(),
+                self.options.sibling_gap,
+                item,
+            );
+        }
+
+        // Sum up the extents from leafs to root
+        // After this step each item has following additional properties set:
+        // 'x_extent_of_children', 'x_extent_children'
+        Self::apply_x_extent(&mut items.0, 0, &self.options);
 
         // Set depth (y_order) on each ItemEmbeddingData structure
-        // After this step each item has following properties set:
-        // 'x_extent', 'text', 'is_emphasized', 'x_extent_children', 'ord', 'parent', 'y_order'
-        Self::apply_y_order(tree, &mut items);
+        // After this step each item has following additional property set: 'y_order'
+        Self::apply_y_order(&mut items);
+        self.apply_dendrogram_levels(tree, &mut items);
 
-        // Finally set the property 'x_center' from leafs to root
+        // Finally set the property 'x_center' from root to leafs
         // After this step each item has all necessary properties set
-        Self::apply_x_center(tree, &mut items);
+        Self::place_root(&mut items, &self.options);
+        Self::apply_x_center(&mut items.0, 0, &self.options);
 
         // Transfer result
         Self::transfer_result(items)
     }
 
-    fn create_initial_embedding_data(tree: &Tree<T>) -> EmbeddingHelperData {
-        fn create_from_node<T: Visualize>(
-            node_id: &NodeId,
-            ord: usize,
-            tree: &Tree<T>,
-            items: &EmbeddingHelperData,
-        ) -> ItemEmbeddingData {
-            let node = tree.get(node_id).unwrap();
-            let text = node.data().visualize();
-            let y_order = 0;
-            let x_center = 0;
-            let x_extent = text.len() + 1;
-            let x_extent_of_children = node.children().iter().fold(0, |acc, child_node_id| {
-                if let Some(placed_item) = items.get_by_node_id(child_node_id) {
-                    acc + placed_item.x_extent_children
+    /// Collects the visible nodes of the (sub-)tree in post-order and links them by their indices.
+    fn collect_virtual_nodes(&self, tree: &Tree<T>, root: Option<&NodeId>) -> Vec<VirtualNode> {
+        let mut nodes: Vec<VirtualNode> = Vec::new();
+
+        if let Some(root_node_id) = root {
+            // Maps each visited tree node to the indices of the visible nodes it contributes to
+            // its parent's child list: itself if it is visible, otherwise the contributions of
+            // its own children.
+            let mut contributions: HashMap<NodeId, Vec<usize>> = HashMap::new();
+            for node_id in tree.traverse_post_order_ids(root_node_id).unwrap() {
+                let children = tree
+                    .children_ids(&node_id)
+                    .unwrap()
+                    .flat_map(|child_node_id| {
+                        // The `id_tree::Tree<T>::traverse_post_order_ids` used to visit the nodes
+                        // should always ensure that child nodes are visited before their parent
+                        // nodes are.
+                        // If you encounter this panic, please report!
+                        contributions
+                            .remove(child_node_id)
+                            .expect("Child node should have already visited!")
+                    })
+                    .collect::<Vec<usize>>();
+
+                let is_hidden = &node_id != root_node_id
+                    && self
+                        .is_hidden
+                        .as_ref()
+                        .is_some_and(|is_hidden| is_hidden(tree.get(&node_id).unwrap().data()));
+                if is_hidden {
+                    contributions.insert(node_id, children);
                 } else {
-                    // The `id_tree::Tree<T>::traverse_post_order_ids` used to visit the nodes
-                    // should always ensure that child nodes are visited before their parent nodes
-                    // are.
-                    // If you encounter this panic, please report!
-                    panic!("Child node should have already visited!");
+                    let index = nodes.len();
+                    for child in &children {
+                        nodes[*child].parent = Some(index);
+                    }
+                    contributions.insert(node_id.clone(), vec![index]);
+                    nodes.push(VirtualNode {
+                        node_id,
+                        children,
+                        parent: None,
+                    });
                 }
-            });
-            let x_extent_children = std::cmp::max(x_extent, x_extent_of_children);
-            let is_emphasized = node.data().emphasize();
-            let parent = None;
-            let node_id = Some(node_id.clone());
-
-            ItemEmbeddingData {
-                y_order,
-                x_center,
-                x_extent,
-                x_extent_of_children,
-                x_extent_children,
-                text,
-                is_emphasized,
-                parent,
-                ord,
-                node_id,
             }
         }
 
+        nodes
+    }
+
+    fn create_initial_embedding_data(
+        &self,
+        tree: &Tree<T>,
+        root: Option<&NodeId>,
+    ) -> EmbeddingHelperData {
+        let nodes = self.collect_virtual_nodes(tree, root);
+
+        // A node is absorbed by its parent if it is the parent's only child and chains are to be
+        // compressed.
+        let is_absorbed = |index: usize| {
+            self.chain_compression.is_some()
+                && nodes[index]
+                    .parent
+                    .is_some_and(|parent| nodes[parent].children.len() == 1)
+        };
+
         let mut items = EmbeddingHelperData::new();
+        // Maps the index of a virtual node that starts an item to the item's `ord`
+        let mut ords = vec![0; nodes.len()];
 
-        if let Some(root_node_id) = tree.root_node_id() {
-            for (ord, node_id) in tree
-                .traverse_post_order_ids(root_node_id)
-                .unwrap()
-                .enumerate()
-            {
-                let new_item = create_from_node(&node_id, ord, tree, &items);
-                items.insert(ord, new_item);
+        for index in 0..nodes.len() {
+            if is_absorbed(index) {
+                continue;
+            }
+
+            let mut chain = vec![index];
+            if self.chain_compression.is_some() {
+                while let [child] = nodes[*chain.last().unwrap()].children[..] {
+                    chain.push(child);
+                }
             }
+
+            let ord = items.0.len();
+            ords[index] = ord;
+
+            // The children of the chain's last node are never absorbed and already have been
+            // inserted because of the post-ordering.
+            let children = nodes[*chain.last().unwrap()]
+                .children
+                .iter()
+                .map(|child| ords[*child])
+                .collect::<Vec<usize>>();
+            for child in &children {
+                items.get_mut_by_ord(*child).unwrap().parent = Some(ord);
+            }
+            let subtree_start = children
+                .first()
+                .map_or(ord, |child| items.get_by_ord(*child).unwrap().subtree_start);
+
+            let chain = if chain.len() > 1 {
+                chain.iter().map(|i| nodes[*i].node_id.clone()).collect()
+            } else {
+                Vec::new()
+            };
+
+            items.push(ItemEmbeddingData {
+                ord,
+                children,
+                subtree_start,
+                chain,
+                node_id: Some(nodes[index].node_id.clone()),
+                ..Default::default()
+            });
         }
 
         items
     }
 
-    fn apply_y_order(tree: &Tree<T>, items: &mut EmbeddingHelperData) {
-        if let Some(root_node_id) = tree.root_node_id() {
-            for node_id in tree.traverse_pre_order_ids(root_node_id).unwrap() {
-                let level = tree.ancestor_ids(&node_id).unwrap().count();
-                let parent = tree
-                    .ancestor_ids(&node_id)
-                    .unwrap()
-                    .next()
-                    .map(|id| items.get_by_node_id(id).unwrap().ord);
-                let item = items.get_mut_by_node_id(&node_id).unwrap();
-                item.y_order = level;
-                item.parent = parent;
-            }
-        };
+    /// The x-extent of an item is its width hint, if there is one, or the extent of its text, plus
+    /// the gap between siblings.
+    pub(crate) fn x_extent(text: &str, width_hint: Option<usize>, sibling_gap: usize) -> usize {
+        width_hint.unwrap_or_else(|| Self::text_extent(text)) + sibling_gap
     }
 
-    fn apply_x_center(tree: &Tree<T>, items: &mut EmbeddingHelperData) {
-        fn x_center_layer(layer: usize, items: &mut EmbeddingHelperData) {
-            let node_ids_in_layer = items.0.iter().fold(Vec::new(), |mut acc, (ord, item)| {
-                if item.y_order == layer {
-                    acc.push(*ord)
-                }
-                acc
-            });
+    /// The extent of a possibly multi-line text is the length of its longest line.
+    pub(crate) fn text_extent(text: &str) -> usize {
+        text.split('\n').map(str::len).max().unwrap_or_default()
+    }
 
-            let parents_in_layer = node_ids_in_layer
+    /// Sets the text representation of the item obtained from the `Visualize` trait.
+    fn apply_label(
+        tree: &Tree<T>,
+        chain_compression: Option<&ChainCompression>,
+        sibling_gap: usize,
+        item: &mut ItemEmbeddingData,
+    ) {
+        let node_datas = if item.chain.is_empty() {
+            vec![tree.get(item.node_id.as_ref().unwrap()).unwrap().data()]
+        } else {
+            item.chain
                 .iter()
-                .map(|ord| items.get_by_ord(*ord).unwrap().parent)
-                .collect::<Vec<Option<usize>>>();
-
-            for p in parents_in_layer {
-                let nodes_in_layer_per_parent = node_ids_in_layer
+                .map(|node_id| tree.get(node_id).unwrap().data())
+                .collect::<Vec<&T>>()
+        };
+        item.text = match chain_compression {
+            Some(compression) if node_datas.len() > 1 => compression.combine(
+                &node_datas
                     .iter()
-                    .filter_map(|ord| {
-                        if items.get_by_ord(*ord).unwrap().parent == p {
-                            Some(*ord)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<usize>>();
+                    .map(|d| d.visualize())
+                    .collect::<Vec<String>>(),
+            ),
+            _ => node_datas[0].visualize(),
+        };
+        item.is_emphasized = node_datas.iter().any(|d| d.emphasize());
+        // The hints only apply to items created from one single node
+        if let [node_data] = node_datas[..] {
+            item.width_hint = node_data.width_hint();
+            item.height_hint = node_data.height_hint();
+        }
+        // Merged items link to the top-most node like their `node_id`
+        item.tooltip = node_datas[0].tooltip();
+        item.url = node_datas[0].url();
+        item.x_extent = Self::x_extent(&item.text, item.width_hint, sibling_gap);
+    }
 
-                let mut moving_x_center = {
-                    if let Some(parent_ord) = p {
-                        if let Some(placed_parent_item) = items.get_by_ord(parent_ord) {
-                            // We start half way left from the parents x center
-                            placed_parent_item.x_center
-                                - placed_parent_item.x_extent_of_children / 2
-                        } else {
-                            // This really should not happen, because the parent_node_id was
-                            // previously retrieved from the tree itself. And the tree is not
-                            // touched at all.
-                            panic!("Some item expected here!")
-                        }
+    /// Sums up the extents of the given items in post-order.
+    /// The `items` are a range of the whole list starting at the `ord` given by `offset`. All
+    /// children of the items need to be contained in the range.
+    fn apply_x_extent(items: &mut [ItemEmbeddingData], offset: usize, options: &LayoutOptions) {
+        for index in 0..items.len() {
+            Self::sum_up_children(items, offset, index, options);
+        }
+    }
+
+    /// Sums up the extents of the children of the item with the given index within the range
+    /// `items` that starts at the `ord` given by `offset`. Adjacent children are separated by the
+    /// subtree gap.
+    fn sum_up_children(
+        items: &mut [ItemEmbeddingData],
+        offset: usize,
+        index: usize,
+        options: &LayoutOptions,
+    ) {
+        let children = &items[index].children;
+        let x_extent_of_children = children.iter().fold(0, |acc, child| {
+            acc + items[child - offset].x_extent_children
+        }) + children.len().saturating_sub(1) * options.subtree_gap;
+        let item = &mut items[index];
+        item.x_extent_of_children = x_extent_of_children;
+        item.x_extent_children = std::cmp::max(item.x_extent, x_extent_of_children);
+    }
+
+    fn apply_y_order(items: &mut EmbeddingHelperData) {
+        // Parents always have a greater `ord` than their children
+        for ord in (0..items.0.len()).rev() {
+            let level = items
+                .get_by_ord(ord)
+                .unwrap()
+                .parent
+                .map_or(0, |parent| items.get_by_ord(parent).unwrap().y_order + 1);
+            items.get_mut_by_ord(ord).unwrap().y_order = level;
+        }
+    }
+
+    /// Moves the items to the levels of the dendrogram mode, if it is enabled.
+    fn apply_dendrogram_levels(&self, tree: &Tree<T>, items: &mut EmbeddingHelperData) {
+        let dendrogram = match &self.dendrogram {
+            Some(dendrogram) => dendrogram,
+            None => return,
+        };
+
+        if let Some(merge_height) = &dendrogram.merge_height {
+            let heights = items
+                .0
+                .iter()
+                .map(|item| {
+                    if item.children.is_empty() {
+                        0.0
                     } else {
-                        // `None` means we are in layer 0
-                        debug_assert_eq!(layer, 0);
-                        // and we should have only one root
-                        debug_assert_eq!(node_ids_in_layer.len(), 1);
-                        // We start all the way left
-                        0
-                    }
-                };
-                for ord in nodes_in_layer_per_parent {
-                    if let Some(placed_item) = items.get_mut_by_ord(ord) {
-                        placed_item.x_center = moving_x_center + placed_item.x_extent_children / 2;
-                        moving_x_center += placed_item.x_extent_children;
+                        let node_id = item.node_id.as_ref().unwrap();
+                        merge_height(tree.get(node_id).unwrap().data()).max(0.0)
                     }
+                })
+                .collect::<Vec<f64>>();
+            // The root is the last item in post-order
+            let root_height = heights.last().cloned().unwrap_or_default();
+            let internal_levels = dendrogram.levels.saturating_sub(1) as f64;
+
+            // Parents always have a greater `ord` than their children, so we visit them first
+            for ord in (0..items.0.len()).rev() {
+                if items.0[ord].children.is_empty() {
+                    continue;
                 }
+                // Heights above the root's one give negative levels which saturate to zero
+                let level = if root_height > 0.0 {
+                    ((1.0 - heights[ord] / root_height) * internal_levels).round() as usize
+                } else {
+                    0
+                };
+                items.0[ord].y_order = match items.0[ord].parent {
+                    Some(parent) => std::cmp::max(level, items.0[parent].y_order + 1),
+                    None => 0,
+                };
+            }
+        }
+
+        let leaf_level = items
+            .0
+            .iter()
+            .filter(|item| !item.children.is_empty())
+            .map(|item| item.y_order + 1)
+            .max()
+            .unwrap_or_default();
+        let leaf_level = std::cmp::max(leaf_level, dendrogram.levels);
+        for item in &mut items.0 {
+            // A root without children stays a root on level 0
+            if item.children.is_empty() && item.parent.is_some() {
+                item.y_order = leaf_level;
             }
         }
+    }
+
+    /// We are at the root and start all the way left.
+    fn place_root(items: &mut EmbeddingHelperData, options: &LayoutOptions) {
+        if let Some(root) = items.0.last_mut() {
+            debug_assert!(root.parent.is_none());
+            root.x_center = Self::x_center_in_slot(root, 0, options);
+        }
+    }
+
+    /// Returns the x center of the item within the slot of its subtree starting at `slot_start`.
+    fn x_center_in_slot(
+        item: &ItemEmbeddingData,
+        slot_start: usize,
+        options: &LayoutOptions,
+    ) -> usize {
+        match options.alignment {
+            Alignment::Left => slot_start + item.x_extent / 2,
+            Alignment::Center => slot_start + item.x_extent_children / 2,
+        }
+    }
 
-        for l in 0..tree.height() + 1 {
-            x_center_layer(l, items);
+    /// Places the children of the given items.
+    /// The `items` are a range of the whole list starting at the `ord` given by `offset`. All
+    /// children of the items need to be contained in the range.
+    fn apply_x_center(items: &mut [ItemEmbeddingData], offset: usize, options: &LayoutOptions) {
+        // Parents always have a greater `ord` than their children, so we visit them first
+        for index in (0..items.len()).rev() {
+            Self::place_children(items, offset, index, options);
         }
     }
 
-    /// Transforming the internal `EmbeddingHelperMap` to the external representation `Embedding`.
+    /// Places the children of the item with the given index within the range `items` that
+    /// starts at the `ord` given by `offset`.
+    fn place_children(
+        items: &mut [ItemEmbeddingData],
+        offset: usize,
+        index: usize,
+        options: &LayoutOptions,
+    ) {
+        let placed_parent_item = &items[index];
+        let mut moving_x_start = match options.alignment {
+            // We start at the left border of the parent's slot
+            Alignment::Left => placed_parent_item.x_center - placed_parent_item.x_extent / 2,
+            // We start half way left from the parents x center
+            Alignment::Center => {
+                placed_parent_item.x_center - placed_parent_item.x_extent_of_children / 2
+            }
+        };
+        let children = placed_parent_item.children.clone();
+        for child in children {
+            let placed_item = &mut items[child - offset];
+            placed_item.x_center = Self::x_center_in_slot(placed_item, moving_x_start, options);
+            moving_x_start += placed_item.x_extent_children + options.subtree_gap;
+        }
+    }
+
+    /// Transforming the internal `EmbeddingHelperData` to the external representation `Embedding`.
     /// The `items` parameter is hereby consumed.
     fn transfer_result(items: EmbeddingHelperData) -> Embedding {
-        let len = items.0.len();
+        Embedding::from_valid_items(
+            items
+                .0
+                .into_iter()
+                .map(PlacedTreeItem::from)
+                .collect::<Vec<PlacedTreeItem>>(),
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Embedder<T>
+where
+    T: Visualize + Sync,
+{
+    ///
+    /// This method creates the same embedding as `embed_tree` but uses all threads of the
+    /// current `rayon` thread pool if the tree has at least as many visible items as the
+    /// threshold set with `with_parallel_threshold`.
+    ///
+    /// The parallel path is opt-in: `embed_tree` and the other methods always embed
+    /// sequentially, even with the `rayon` feature enabled, since they don't require the node
+    /// data to be `Sync`. This method is only available with the `rayon` feature.
+    ///
+    /// The items are collected sequentially in one traversal of the tree. Then their labels are
+    /// obtained in parallel, and the tree is split into disjoint subtrees of similar size which
+    /// are measured and placed in parallel. Only the items above these subtrees are measured
+    /// and placed sequentially.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn par_embed_tree(&self, tree: &Tree<T>) -> Embedding {
+        let mut items = self.create_initial_embedding_data(tree, tree.root_node_id());
+        if items.0.len() < std::cmp::max(self.parallel_threshold, 1) {
+            return self.embed_items(tree, items);
+        }
+
+        let chain_compression = self.chain_compression.as_ref();
+        let options = &self.options;
         items
             .0
+            .par_iter_mut()
+            .for_each(|item| Self::apply_label(tree, chain_compression, options.sibling_gap, item));
+
+        let grain = std::cmp::max(items.0.len() / (rayon::current_num_threads() * 4), 1);
+        let (top, subtrees) = Self::partition(&items, grain);
+
+        Self::subtree_ranges(&mut items.0, &subtrees)
+            .into_par_iter()
+            .for_each(|(offset, range)| Self::apply_x_extent(range, offset, options));
+        for ord in &top {
+            Self::sum_up_children(&mut items.0, 0, *ord, options);
+        }
+
+        Self::apply_y_order(&mut items);
+        self.apply_dendrogram_levels(tree, &mut items);
+
+        Self::place_root(&mut items, options);
+        for ord in top.iter().rev() {
+            Self::place_children(&mut items.0, 0, *ord, options);
+        }
+        Self::subtree_ranges(&mut items.0, &subtrees)
+            .into_par_iter()
+            .for_each(|(offset, range)| Self::apply_x_center(range, offset, options));
+
+        Self::transfer_result(items)
+    }
+
+    /// Splits the items into the roots of the subtrees with less than `grain` items and the
+    /// remaining items above them, both in ascending order.
+    fn partition(items: &EmbeddingHelperData, grain: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut top = Vec::new();
+        let mut subtrees = Vec::new();
+        let mut stack = items
+            .0
+            .len()
+            .checked_sub(1)
             .into_iter()
-            .fold(Embedding::with_capacity(len), |mut acc, e| {
-                acc.push(e.1.into());
-                acc
-            })
+            .collect::<Vec<usize>>();
+        while let Some(ord) = stack.pop() {
+            let item = items.get_by_ord(ord).unwrap();
+            if ord - item.subtree_start < grain {
+                subtrees.push(ord);
+            } else {
+                top.push(ord);
+                stack.extend(&item.children);
+            }
+        }
+        top.sort_unstable();
+        subtrees.sort_unstable();
+        (top, subtrees)
+    }
+
+    /// Splits the items into the ranges of the given subtrees, each with the `ord` it starts at.
+    fn subtree_ranges<'a>(
+        items: &'a mut [ItemEmbeddingData],
+        subtrees: &[usize],
+    ) -> Vec<(usize, &'a mut [ItemEmbeddingData])> {
+        let mut ranges = Vec::new();
+        let mut rest = items;
+        let mut offset = 0;
+        for ord in subtrees {
+            let start = rest[ord - offset].subtree_start;
+            let (_, tail) = rest.split_at_mut(start - offset);
+            let (range, tail) = tail.split_at_mut(ord + 1 - start);
+            ranges.push((start, range));
+            rest = tail;
+            offset = ord + 1;
+        }
+        ranges
     }
 }
@@ -0,0 +1,48 @@
+//! The module with the Error type of creating an `Embedding` from a list of items.
+use std::fmt;
+
+///
+/// The EmbeddingError type distinguishes the causes why a list of `PlacedTreeItem`s doesn't form
+/// an `Embedding`. New causes may be added in the future, thus matches need a wildcard arm.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EmbeddingError {
+    /// The `ord`s aren't the numbers from zero to the number of items minus one, the value is an
+    /// `ord` that is duplicated or follows a gap
+    InvalidOrd(usize),
+    /// The `parent` of an item doesn't refer to an item with a greater `ord`
+    InvalidParent {
+        /// The `ord` of the item
+        ord: usize,
+        /// The `ord` the item refers to as its parent
+        parent: usize,
+    },
+    /// The text of an item extends left of the logical x coordinate zero, i.e. its `x_center` is
+    /// less than half its `x_extent`, the value is the item's `ord`
+    InvalidExtent(usize),
+}
+
+impl fmt::Display for EmbeddingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbeddingError::InvalidOrd(ord) => write!(
+                f,
+                "Invalid ord: {} is duplicated or follows a gap, the ords need to be unique and dense",
+                ord
+            ),
+            EmbeddingError::InvalidParent { ord, parent } => write!(
+                f,
+                "Invalid parent: the parent {} of item {} is not an item with a greater ord",
+                parent, ord
+            ),
+            EmbeddingError::InvalidExtent(ord) => write!(
+                f,
+                "Invalid extent: the text of item {} extends left of zero",
+                ord
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EmbeddingError {}
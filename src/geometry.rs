@@ -0,0 +1,377 @@
+//! The module that transforms an embedding into concrete floating-point geometry.
+//!
+//! The `Embedding` only provides logical coordinates, i.e. columns and levels. The `Geometry`
+//! created from it contains the rectangles of all nodes, the anchor points of the edges and the
+//! size of the whole canvas, so that drawers only have to paint.
+
+use crate::embedder::Embedding;
+use crate::layout_options::Orientation;
+use crate::spatial_index::SpatialIndex;
+
+///
+/// A point in the plane.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+///
+/// The size of a rectangular area.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+///
+/// An axis-aligned rectangle given by its upper left corner and its size.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// The x coordinate of the right border
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    /// The y coordinate of the lower border
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// The center point of the rectangle
+    pub fn center(&self) -> Point {
+        Point {
+            x: self.x + self.width / 2.0,
+            y: self.y + self.height / 2.0,
+        }
+    }
+
+    /// Returns true if the point lies within the rectangle or on its border.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.x <= self.right()
+            && point.y >= self.y
+            && point.y <= self.bottom()
+    }
+
+    /// Returns true if both rectangles have at least one point in common.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x <= other.right()
+            && other.x <= self.right()
+            && self.y <= other.bottom()
+            && other.y <= self.bottom()
+    }
+}
+
+///
+/// The `TextMeasurer` trait abstracts the measuring of the nodes' texts.
+/// Implement it if your drawer uses fonts with other metrics than the ones the
+/// `MonospaceMeasurer` provides.
+///
+pub trait TextMeasurer {
+    /// Returns the size of the given text. Texts can contain several lines separated by '\n'.
+    fn measure(&self, text: &str) -> Size;
+
+    /// Returns the height of one single line of text.
+    fn line_height(&self) -> f64;
+}
+
+///
+/// A `TextMeasurer` for monospaced fonts.
+/// Its default values are the ones the `SvgDrawer` uses.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonospaceMeasurer {
+    /// The width of one character
+    pub char_width: f64,
+    /// The height of one line
+    pub line_height: f64,
+}
+
+impl Default for MonospaceMeasurer {
+    fn default() -> Self {
+        Self {
+            char_width: 10.0,
+            line_height: 10.0,
+        }
+    }
+}
+
+impl TextMeasurer for MonospaceMeasurer {
+    fn measure(&self, text: &str) -> Size {
+        let lines = text.split('\n');
+        Size {
+            width: lines.clone().map(str::len).max().unwrap_or_default() as f64 * self.char_width,
+            height: lines.count() as f64 * self.line_height,
+        }
+    }
+
+    fn line_height(&self) -> f64 {
+        self.line_height
+    }
+}
+
+///
+/// The `Spacing` type configures how logical coordinates of an embedding are scaled.
+/// Its default values are the ones the `SvgDrawer` uses.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    /// The width of one logical x coordinate unit
+    pub unit_width: f64,
+    /// The vertical distance between the tops of two consecutive levels with texts of one line,
+    /// a level with higher texts is followed by the additional height
+    pub level_height: f64,
+    /// The distance of the left-most logical x coordinate to the left border of the canvas
+    pub margin_x: f64,
+    /// The distance of the first level's bottom to the upper border of the canvas
+    pub margin_y: f64,
+    /// The vertical gap between the bottom of a node and the edges to its children
+    pub edge_gap: f64,
+    /// The direction in which the levels follow each other
+    pub orientation: Orientation,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            unit_width: 10.0,
+            level_height: 35.0,
+            margin_x: 10.0,
+            margin_y: 25.0,
+            edge_gap: 10.0,
+            orientation: Orientation::TopDown,
+        }
+    }
+}
+
+///
+/// The geometry of one single node.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NodeGeometry {
+    /// The `ord` of the corresponding `PlacedTreeItem`
+    pub ord: usize,
+    /// The parent's `ord`, if there is one
+    pub parent: Option<usize>,
+    /// The node's level, root has level 0
+    pub y_order: usize,
+    /// The rectangle the node's text occupies
+    pub rect: Rect,
+    /// The point where the edge from the parent ends, at the bottom with orientation `BottomUp`
+    pub top_anchor: Point,
+    /// The point where the edges to the children start, at the top with orientation `BottomUp`
+    pub bottom_anchor: Point,
+}
+
+///
+/// The geometry of the edge between a node and its parent.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Edge {
+    /// The `ord` of the parent node
+    pub parent: usize,
+    /// The `ord` of the child node
+    pub child: usize,
+    /// The start point at the parent's bottom anchor
+    pub from: Point,
+    /// The end point at the child's top anchor
+    pub to: Point,
+}
+
+///
+/// The `Geometry` type holds the floating-point geometry of a whole embedding.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Geometry {
+    /// The nodes ordered by `ord`
+    nodes: Vec<NodeGeometry>,
+    /// The rectangles of the captions of a forest
+    captions: Vec<Rect>,
+    /// The size of the canvas needed to paint all nodes
+    size: Size,
+}
+
+impl Geometry {
+    ///
+    /// Creates the geometry of the given embedding.
+    ///
+    /// A node's text rectangle is horizontally centered at the node's scaled `x_center`. Its
+    /// bottom is placed at the level's scaled y coordinate, i.e. `y_order * level_height +
+    /// margin_y`, for texts with several lines this applies to the first line.
+    /// The size of the rectangle is measured from the text unless the item has size hints, a
+    /// width hint is scaled by `unit_width` and a height hint by the measurer's line height.
+    /// If the embedding contains captions they occupy an additional row above the first level.
+    /// With the orientation `BottomUp` the order of the levels is reversed and the edges connect
+    /// the tops of the parents with the bottoms of their children.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn new(embedding: &Embedding, measurer: &dyn TextMeasurer, spacing: &Spacing) -> Self {
+        let caption_rows = if embedding.captions().is_empty() {
+            0
+        } else {
+            1
+        };
+        let bounding_box = embedding.bounding_box();
+        let scale_x = |x: usize| x as f64 * spacing.unit_width + spacing.margin_x;
+        let row = |y_order: usize| match spacing.orientation {
+            Orientation::TopDown => y_order,
+            Orientation::BottomUp => bounding_box.height - 1 - y_order,
+        };
+
+        let text_sizes = embedding
+            .iter()
+            .map(|item| {
+                let text_size = measurer.measure(&item.text);
+                Size {
+                    width: item
+                        .width_hint
+                        .map_or(text_size.width, |w| w as f64 * spacing.unit_width),
+                    height: item
+                        .height_hint
+                        .map_or(text_size.height, |h| h as f64 * measurer.line_height()),
+                }
+            })
+            .collect::<Vec<Size>>();
+        // Each row is as high as its highest text, the baselines of the first lines follow
+        // each other by the level height plus the additional lines of the row above
+        let mut row_heights = vec![measurer.line_height(); bounding_box.height];
+        for (item, text_size) in embedding.iter().zip(&text_sizes) {
+            let row_height = &mut row_heights[row(item.y_order)];
+            *row_height = row_height.max(text_size.height);
+        }
+        let mut baselines = vec![caption_rows as f64 * spacing.level_height + spacing.margin_y];
+        for row_height in &row_heights {
+            let baseline = baselines[baselines.len() - 1];
+            baselines.push(baseline + spacing.level_height + row_height - measurer.line_height());
+        }
+
+        let nodes = embedding
+            .iter()
+            .zip(&text_sizes)
+            .map(|(item, text_size)| {
+                let x = scale_x(item.x_center);
+                let rect = Rect {
+                    x: x - text_size.width / 2.0,
+                    y: baselines[row(item.y_order)] - measurer.line_height(),
+                    width: text_size.width,
+                    height: text_size.height,
+                };
+                let top = Point { x, y: rect.y };
+                let bottom = Point {
+                    x,
+                    y: rect.bottom() + spacing.edge_gap,
+                };
+                let (top_anchor, bottom_anchor) = match spacing.orientation {
+                    Orientation::TopDown => (top, bottom),
+                    Orientation::BottomUp => (bottom, top),
+                };
+                NodeGeometry {
+                    ord: item.ord,
+                    parent: item.parent,
+                    y_order: item.y_order,
+                    rect,
+                    top_anchor,
+                    bottom_anchor,
+                }
+            })
+            .collect();
+
+        let captions = embedding
+            .captions()
+            .iter()
+            .map(|caption| {
+                let text_size = measurer.measure(&caption.text);
+                Rect {
+                    x: scale_x(caption.x_center) - text_size.width / 2.0,
+                    y: spacing.margin_y - measurer.line_height(),
+                    width: text_size.width,
+                    height: text_size.height,
+                }
+            })
+            .collect();
+
+        let size = Size {
+            width: scale_x(bounding_box.x + bounding_box.width),
+            height: baselines[bounding_box.height],
+        };
+
+        Self {
+            nodes,
+            captions,
+            size,
+        }
+    }
+
+    ///
+    /// Returns the geometry of all nodes ordered by `ord`.
+    ///
+    pub fn nodes(&self) -> &[NodeGeometry] {
+        &self.nodes
+    }
+
+    ///
+    /// Returns the geometry of the node with the given `ord`.
+    ///
+    pub fn node(&self, ord: usize) -> Option<&NodeGeometry> {
+        self.nodes.get(ord)
+    }
+
+    ///
+    /// Returns the rectangles of the captions in the order of `Embedding::captions`.
+    ///
+    pub fn captions(&self) -> &[Rect] {
+        &self.captions
+    }
+
+    ///
+    /// Returns the edge from the parent of the node with the given `ord` to the node itself.
+    ///
+    pub fn edge_to(&self, ord: usize) -> Option<Edge> {
+        let node = self.nodes.get(ord)?;
+        let parent = &self.nodes[node.parent?];
+        Some(Edge {
+            parent: parent.ord,
+            child: node.ord,
+            from: parent.bottom_anchor,
+            to: node.top_anchor,
+        })
+    }
+
+    ///
+    /// Returns all edges ordered by the child's `ord`.
+    ///
+    pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        self.nodes
+            .iter()
+            .filter_map(move |node| self.edge_to(node.ord))
+    }
+
+    ///
+    /// Returns the size of the canvas needed to paint all nodes.
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    ///
+    /// Creates an index for hit testing in the coordinates of this geometry.
+    /// The area of a node is its text rectangle.
+    ///
+    pub fn spatial_index(&self) -> SpatialIndex {
+        SpatialIndex::from_geometry(self)
+    }
+}
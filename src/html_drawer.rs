@@ -0,0 +1,127 @@
+//! The module with the drawer that writes an interactive Html page.
+
+use crate::drawer::{self, Drawer};
+use crate::embedder::Embedding;
+use crate::svg_drawer::SvgDrawer;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// The rules that hide the children of collapsed nodes
+const STYLE: &str = r#"g.node.collapsed > g[role="group"] { display: none; }
+g.node:not(.leaf) > text { cursor: pointer; }
+g.node.collapsed > text { text-decoration: underline dotted; }"#;
+
+/// The script that collapses and expands the nodes by mouse and keyboard
+const SCRIPT: &str = r#"(function () {
+  var tree = document.currentScript.previousElementSibling;
+  tree.querySelectorAll("g.node:not(.leaf)").forEach(function (node) {
+    node.setAttribute("aria-expanded", "true");
+    node.setAttribute("tabindex", "0");
+  });
+  function toggle(event) {
+    if (event.target.closest("a")) {
+      return;
+    }
+    var node = event.target.closest("g.node");
+    if (node && !node.classList.contains("leaf")) {
+      var collapsed = node.classList.toggle("collapsed");
+      node.setAttribute("aria-expanded", collapsed ? "false" : "true");
+      event.preventDefault();
+    }
+  }
+  tree.addEventListener("click", toggle);
+  tree.addEventListener("keydown", function (event) {
+    if (event.key === "Enter" || event.key === " ") {
+      toggle(event);
+    }
+  });
+})();"#;
+
+///
+/// The `HtmlDrawer` type writes a standalone Html page that contains the tree as the
+/// `SvgDrawer` paints it. Clicking a node, or pressing enter on the focused node, collapses its
+/// subtree and expands it again, the positions of the other nodes don't change.
+///
+/// ```
+/// use id_tree_layout::{Drawer, Embedder, HtmlDrawer, SvgDrawer, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+///
+/// let drawer = HtmlDrawer::new().with_drawer(SvgDrawer::new().with_title("My tree"));
+/// let file_name = std::env::temp_dir().join("tree.html");
+/// drawer.draw(&file_name, &Embedder::embed(&tree)).unwrap();
+/// assert!(std::fs::read_to_string(&file_name).unwrap().contains("<title>My tree</title>"));
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct HtmlDrawer {
+    /// The drawer that paints the tree
+    drawer: SvgDrawer,
+}
+
+impl HtmlDrawer {
+    /// Method to create a fresh instance of the `HtmlDrawer` type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the drawer whose options, themes and style sheet are used to paint the tree. Its
+    /// title is also the title of the page.
+    ///
+    pub fn with_drawer(self, drawer: SvgDrawer) -> Self {
+        Self { drawer }
+    }
+}
+
+impl Drawer for HtmlDrawer {
+    ///
+    /// Writes the page with the embedded Svg.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    fn draw(&self, file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        let mut out = BufWriter::new(File::create(file_name)?);
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html lang=\"en\">")?;
+        writeln!(out, "<head>")?;
+        writeln!(out, "<meta charset=\"utf-8\">")?;
+        writeln!(
+            out,
+            "<title>{}</title>",
+            escape(self.drawer.title().unwrap_or("Tree"))
+        )?;
+        writeln!(out, "<style>\n{}\n</style>", STYLE)?;
+        writeln!(out, "</head>")?;
+        writeln!(out, "<body>")?;
+        {
+            let mut xml = SvgDrawer::create_writer(&mut out);
+            self.drawer.write_svg(&mut xml, embedding)?;
+            xml.end_elem()?;
+            xml.flush()?;
+        }
+        writeln!(out, "\n<script>\n{}\n</script>", SCRIPT)?;
+        writeln!(out, "</body>")?;
+        writeln!(out, "</html>")?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Escapes the characters with a special meaning in Html texts.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
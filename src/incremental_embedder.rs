@@ -0,0 +1,304 @@
+//! The module with the stateful embedder for trees that change frequently.
+
+use crate::embedder::{Embedder, Embedding, PlacedTreeItem, Segment};
+use crate::layout_options::{Alignment, LayoutOptions};
+use crate::visualize::Visualize;
+use id_tree::{NodeId, Tree};
+use std::collections::{HashMap, HashSet};
+
+///
+/// The cached embedding information of one single tree node and its subtree.
+///
+#[derive(Debug, Clone, Default)]
+struct CachedNode {
+    /// The text representation of the nodes data
+    text: String,
+    /// The *emphasize* property obtained from the `Visualize` trait
+    is_emphasized: bool,
+    /// The width hint obtained from the `Visualize` trait
+    width_hint: Option<usize>,
+    /// The height hint obtained from the `Visualize` trait
+    height_hint: Option<usize>,
+    /// The tooltip obtained from the `Visualize` trait
+    tooltip: Option<String>,
+    /// The URL obtained from the `Visualize` trait
+    url: Option<String>,
+    /// The x-extent of the nodes text representation in logical coordinate units
+    x_extent: usize,
+    /// The sum of the x-extent of all children of the node
+    x_extent_of_children: usize,
+    /// The maximum extent over the nodes text representation and the sum of all children's x-extent
+    x_extent_children: usize,
+    /// The start of the node's slot relative to the start of its parent's children
+    x_offset: usize,
+    /// The number of nodes of the subtree
+    size: usize,
+}
+
+///
+/// The IncrementalEmbedder type creates the same embedding as `Embedder::embed` but keeps the
+/// extents of all subtrees and the embedding between two calls. When the tree changes only the
+/// changed nodes and their ancestors are measured again. The items of the embedding are replaced
+/// from the first item on whose `ord` or position changed, the unchanged subtrees right of it
+/// are moved as a whole without calling the `Visualize` trait's methods.
+///
+/// ```
+/// use id_tree_layout::{Embedder, IncrementalEmbedder, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+///
+/// let mut embedder = IncrementalEmbedder::new();
+/// let embedding = embedder.embed(&tree);
+///
+/// let child = tree.insert(Node::new(MyNodeData(100)), UnderNode(&root)).unwrap();
+/// let embedding = embedder.update(&tree, &[child]);
+/// assert_eq!(Embedder::embed(&tree)[..], embedding[..]);
+/// ```
+///
+pub struct IncrementalEmbedder<T>
+where
+    T: Visualize,
+{
+    cache: HashMap<NodeId, CachedNode>,
+    embedding: Embedding,
+    ords: HashMap<NodeId, usize>,
+    options: LayoutOptions,
+    _1: std::marker::PhantomData<T>,
+}
+
+impl<T> Default for IncrementalEmbedder<T>
+where
+    T: Visualize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IncrementalEmbedder<T>
+where
+    T: Visualize,
+{
+    ///
+    /// Creates a new IncrementalEmbedder with an empty cache.
+    ///
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            embedding: Embedding::default(),
+            ords: HashMap::new(),
+            options: LayoutOptions::default(),
+            _1: std::marker::PhantomData,
+        }
+    }
+
+    ///
+    /// Sets the layout options, which are applied the same way `Embedder::with_options` does.
+    ///
+    pub fn with_options(self, options: LayoutOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    ///
+    /// Creates the embedding of the whole tree from scratch, fills the cache and keeps the
+    /// embedding for the next update.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn embed(&mut self, tree: &Tree<T>) -> &Embedding {
+        self.cache.clear();
+        self.embedding = Embedding::default();
+        self.ords.clear();
+        if let Some(root_node_id) = tree.root_node_id() {
+            for node_id in tree.traverse_post_order_ids(root_node_id).unwrap() {
+                self.update_node(tree, &node_id, true);
+            }
+        }
+        self.transfer_result(tree, None)
+    }
+
+    ///
+    /// Updates the cache and the kept embedding of the whole tree after the tree was changed.
+    ///
+    /// The `changed` nodes need to contain
+    /// * all nodes whose data changed,
+    /// * all inserted nodes,
+    /// * all nodes whose children changed, i.e. the parents of inserted, removed or moved nodes.
+    ///
+    /// Ids of nodes that don't exist anymore are ignored.
+    ///
+    /// # Complexity
+    ///
+    /// The cache is updated in O(c·d) where c is the number of changed nodes and d is the depth
+    /// of the tree. The embedding is updated in O(c·d·b + s) where b is the greatest number of
+    /// children of a node and s the number of items from the first item on whose `ord` or
+    /// position changed, i.e. the changed nodes, their ancestors and the subtrees to their right.
+    ///
+    pub fn update(&mut self, tree: &Tree<T>, changed: &[NodeId]) -> &Embedding {
+        let changed = changed
+            .iter()
+            .filter(|node_id| tree.get(node_id).is_ok())
+            .collect::<HashSet<&NodeId>>();
+
+        // Collect the changed nodes and all their ancestors together with their depth
+        let mut dirty: HashMap<NodeId, usize> = HashMap::new();
+        for node_id in &changed {
+            if dirty.contains_key(*node_id) {
+                continue;
+            }
+            let ancestors = tree
+                .ancestor_ids(node_id)
+                .unwrap()
+                .collect::<Vec<&NodeId>>();
+            let depth = ancestors.len();
+            dirty.insert((*node_id).clone(), depth);
+            for (i, ancestor) in ancestors.into_iter().enumerate() {
+                if dirty.insert(ancestor.clone(), depth - i - 1).is_some() {
+                    // All further ancestors have already been inserted
+                    break;
+                }
+            }
+        }
+
+        // Children need to be updated before their parents
+        let mut dirty = dirty.into_iter().collect::<Vec<(NodeId, usize)>>();
+        dirty.sort_by(|(_, d1), (_, d2)| d2.cmp(d1));
+        for (node_id, _) in &dirty {
+            let data_changed = changed.contains(node_id) || !self.cache.contains_key(node_id);
+            self.update_node(tree, node_id, data_changed);
+        }
+
+        let dirty = dirty
+            .into_iter()
+            .map(|(node_id, _)| node_id)
+            .collect::<HashSet<NodeId>>();
+        self.transfer_result(tree, Some(&dirty))
+    }
+
+    /// Updates the cached data of one node whose children are already up to date.
+    fn update_node(&mut self, tree: &Tree<T>, node_id: &NodeId, data_changed: bool) {
+        let node = tree.get(node_id).unwrap();
+
+        let mut x_offset = 0;
+        let mut size = 1;
+        for child_node_id in node.children() {
+            // Children are always updated before their parents.
+            // If you encounter this panic, please report!
+            let child = self
+                .cache
+                .get_mut(child_node_id)
+                .expect("Child node should have already visited!");
+            child.x_offset = x_offset;
+            x_offset += child.x_extent_children + self.options.subtree_gap;
+            size += child.size;
+        }
+        let x_extent_of_children = x_offset.saturating_sub(self.options.subtree_gap);
+
+        let cached = self.cache.entry(node_id.clone()).or_default();
+        if data_changed {
+            cached.text = node.data().visualize();
+            cached.is_emphasized = node.data().emphasize();
+            cached.width_hint = node.data().width_hint();
+            cached.height_hint = node.data().height_hint();
+            cached.tooltip = node.data().tooltip();
+            cached.url = node.data().url();
+            cached.x_extent =
+                Embedder::<T>::x_extent(&cached.text, cached.width_hint, self.options.sibling_gap);
+        }
+        cached.x_extent_of_children = x_extent_of_children;
+        cached.x_extent_children = std::cmp::max(cached.x_extent, x_extent_of_children);
+        cached.size = size;
+    }
+
+    /// Transfers the cached data into the kept embedding in one pass over the `dirty` nodes, or
+    /// over all nodes if there are none given. The subtrees of clean nodes are moved as a whole.
+    fn transfer_result(&mut self, tree: &Tree<T>, dirty: Option<&HashSet<NodeId>>) -> &Embedding {
+        let mut segments: Vec<Segment> = Vec::new();
+
+        if let Some(root_node_id) = tree.root_node_id() {
+            let mut next_ord = 0;
+            // The nodes with the start of their slot, their level and whether their children
+            // have been visited
+            let mut stack = vec![(root_node_id, 0, 0, false)];
+            // The `ord`s of the visited children of the nodes on the stack
+            let mut children: Vec<Vec<usize>> = Vec::new();
+            while let Some((node_id, slot_start, y_order, is_visited)) = stack.pop() {
+                let cached = &self.cache[node_id];
+                let x_center = match self.options.alignment {
+                    Alignment::Left => slot_start + cached.x_extent / 2,
+                    Alignment::Center => slot_start + cached.x_extent_children / 2,
+                };
+                let ord = if dirty.is_some_and(|dirty| !dirty.contains(node_id)) {
+                    next_ord += cached.size;
+                    segments.push(Segment::Subtree {
+                        root: self.ords[node_id],
+                        size: cached.size,
+                        ord: next_ord - 1,
+                        x_center,
+                        y_order,
+                    });
+                    next_ord - 1
+                } else if !is_visited {
+                    let children_start = match self.options.alignment {
+                        Alignment::Left => slot_start,
+                        Alignment::Center => x_center - cached.x_extent_of_children / 2,
+                    };
+                    stack.push((node_id, slot_start, y_order, true));
+                    for child_node_id in tree.get(node_id).unwrap().children().iter().rev() {
+                        let child_slot_start = children_start + self.cache[child_node_id].x_offset;
+                        stack.push((child_node_id, child_slot_start, y_order + 1, false));
+                    }
+                    children.push(Vec::new());
+                    continue;
+                } else {
+                    let item = Box::new(PlacedTreeItem {
+                        y_order,
+                        x_center,
+                        x_extent: cached.x_extent,
+                        x_extent_children: cached.x_extent_children,
+                        text: cached.text.clone(),
+                        is_emphasized: cached.is_emphasized,
+                        width_hint: cached.width_hint,
+                        height_hint: cached.height_hint,
+                        tooltip: cached.tooltip.clone(),
+                        url: cached.url.clone(),
+                        ord: next_ord,
+                        node_id: Some(node_id.clone()),
+                        ..Default::default()
+                    });
+                    segments.push(Segment::Item(item, children.pop().unwrap()));
+                    next_ord += 1;
+                    next_ord - 1
+                };
+                if let Some(siblings) = children.last_mut() {
+                    siblings.push(ord);
+                }
+            }
+        }
+
+        let (start, replaced) = self.embedding.splice(segments);
+        // Forget the removed nodes, their ids may have been reused for inserted nodes though
+        for node_id in replaced.into_iter().filter_map(|item| item.node_id) {
+            if tree.get(&node_id).is_err() {
+                self.ords.remove(&node_id);
+                self.cache.remove(&node_id);
+            }
+        }
+        for item in &self.embedding[start..] {
+            if let Some(node_id) = &item.node_id {
+                self.ords.insert(node_id.clone(), item.ord);
+            }
+        }
+        &self.embedding
+    }
+}
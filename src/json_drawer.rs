@@ -0,0 +1,135 @@
+//! The module with the drawer that exports the embedding as JSON.
+
+use crate::diff::DiffStatus;
+use crate::drawer::{self, Drawer, DrawerError};
+use crate::embedder::{Caption, Embedding, PlacedTreeItem};
+use serde_derive::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+///
+/// The `JsonDrawer` type exports the embedding as JSON, e.g. to draw it with a web front end.
+///
+/// The document holds the `width` and the `height` of the embedding in logical coordinates,
+/// the `captions` of a forest and the `items` ordered by their `ord`. Each item holds its
+/// position, its text and the properties obtained from the `Visualize` trait, the `ord`s of its
+/// `parent` and its `children` and, in the embedding of a diff, its `diff_status`.
+///
+/// ```
+/// use id_tree_layout::{Drawer, Embedder, JsonDrawer, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+///
+/// let file_name = std::env::temp_dir().join("tree.json");
+/// JsonDrawer::new().draw(&file_name, &Embedder::embed(&tree)).unwrap();
+/// assert!(std::fs::read_to_string(&file_name).unwrap().contains("\"text\": \"0\""));
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct JsonDrawer;
+
+impl JsonDrawer {
+    /// Method to create a fresh instance of the `JsonDrawer` type.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// The exported embedding
+#[derive(Serialize)]
+struct JsonEmbedding<'a> {
+    width: usize,
+    height: usize,
+    captions: &'a [Caption],
+    items: Vec<JsonItem<'a>>,
+}
+
+/// The exported item
+#[derive(Serialize)]
+struct JsonItem<'a> {
+    ord: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    y_order: usize,
+    x_center: usize,
+    x_extent: usize,
+    x_extent_children: usize,
+    text: &'a str,
+    is_emphasized: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width_hint: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height_hint: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tooltip: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff_status: Option<DiffStatus>,
+}
+
+impl<'a> JsonItem<'a> {
+    fn new(embedding: &Embedding, item: &'a PlacedTreeItem) -> Self {
+        Self {
+            ord: item.ord,
+            parent: item.parent,
+            children: embedding
+                .children(item.ord)
+                .map(|child| child.ord)
+                .collect(),
+            y_order: item.y_order,
+            x_center: item.x_center,
+            x_extent: item.x_extent,
+            x_extent_children: item.x_extent_children,
+            text: &item.text,
+            is_emphasized: item.is_emphasized,
+            width_hint: item.width_hint,
+            height_hint: item.height_hint,
+            tooltip: item.tooltip.as_deref(),
+            url: item.url.as_deref(),
+            diff_status: embedding.diff_status(item.ord),
+        }
+    }
+}
+
+impl Drawer for JsonDrawer {
+    ///
+    /// Writes the embedding as pretty printed JSON.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    fn draw(&self, file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        let bounding_box = embedding.bounding_box();
+        let document = JsonEmbedding {
+            width: bounding_box.x + bounding_box.width,
+            height: bounding_box.y + bounding_box.height,
+            captions: embedding.captions(),
+            items: embedding
+                .iter()
+                .map(|item| JsonItem::new(embedding, item))
+                .collect(),
+        };
+        let mut out = BufWriter::new(File::create(file_name)?);
+        serde_json::to_writer_pretty(&mut out, &document).map_err(|err| {
+            if err.is_io() {
+                DrawerError::Io(err.into())
+            } else {
+                DrawerError::other(err)
+            }
+        })?;
+        writeln!(out)?;
+        out.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! The module with the layout options shared by the embedders and the drawers.
+
+#[cfg(feature = "serde_support")]
+use serde_derive::{Deserialize, Serialize};
+
+///
+/// The direction in which the levels of a tree follow each other.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Orientation {
+    /// The root is at the top, the levels grow downwards
+    #[default]
+    TopDown,
+    /// The root is at the bottom, the levels grow upwards
+    BottomUp,
+}
+
+///
+/// The horizontal alignment of a parent relative to the slot reserved for its subtree.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Alignment {
+    /// The parent is centered over its subtree and the children are centered under the parent
+    #[default]
+    Center,
+    /// The parent and its children start at the left border of the subtree's slot
+    Left,
+}
+
+///
+/// The `LayoutOptions` type collects the spacing decisions of the `Embedder` and the drawers,
+/// so that one configuration leads to consistent output.
+/// Horizontal distances are given in logical coordinate units, i.e. in characters, vertical
+/// distances in lines of text and margins in the units of the drawer's output.
+/// Its default values reproduce the layout of the crate's `Embedder` and `SvgDrawer` without
+/// any options.
+///
+/// With the feature `serde_support` the options can be read from a configuration file, missing
+/// values are taken from the defaults.
+///
+/// ```
+/// use id_tree_layout::LayoutOptions;
+/// use id_tree_layout::layout_options::Orientation;
+///
+/// let options = LayoutOptions {
+///     subtree_gap: 2,
+///     orientation: Orientation::BottomUp,
+///     ..Default::default()
+/// };
+/// assert_eq!(1, options.sibling_gap);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(default))]
+pub struct LayoutOptions {
+    /// The horizontal space added to the extent of each node
+    pub sibling_gap: usize,
+    /// The additional horizontal space between the subtrees of two adjacent siblings
+    pub subtree_gap: usize,
+    /// The horizontal space between the trees of a forest
+    pub tree_gap: usize,
+    /// The empty vertical space between the last line of a level and the first line of the next
+    /// one, thus the first lines of two consecutive one-line levels are `1 + level_gap` apart
+    pub level_gap: f64,
+    /// The distance of the left-most node to the left border of the output
+    pub margin_x: f64,
+    /// The distance of the first level's baseline to the upper border of the output
+    pub margin_y: f64,
+    /// The direction in which the levels follow each other
+    pub orientation: Orientation,
+    /// The alignment of parents relative to their subtrees
+    pub alignment: Alignment,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            sibling_gap: 1,
+            subtree_gap: 0,
+            tree_gap: 2,
+            level_gap: 2.5,
+            margin_x: 10.0,
+            margin_y: 25.0,
+            orientation: Orientation::TopDown,
+            alignment: Alignment::Center,
+        }
+    }
+}
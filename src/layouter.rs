@@ -1,7 +1,13 @@
 //! The module with the **Public API that is highly encouraged to be used**.
 use crate::layouter_error;
-use crate::{Drawer, Embedder, LayouterError, SvgDrawer, Visualize};
-use id_tree::Tree;
+#[cfg(feature = "serde_support")]
+use crate::JsonDrawer;
+use crate::{
+    AsciiDrawer, DotDrawer, Drawer, Embedder, Embedding, HtmlDrawer, LayoutOptions, LayouterError,
+    SvgDrawer, Visualize,
+};
+use id_tree::{NodeId, Tree};
+use std::path::Path;
 
 ///
 /// The Result type that is uses within the public API `Layouter`.
@@ -18,6 +24,10 @@ where
     tree: &'a Tree<T>,
     drawer: Option<&'b dyn Drawer>,
     file_name: Option<&'c std::path::Path>,
+    root: Option<NodeId>,
+    node_limit: Option<usize>,
+    options: LayoutOptions,
+    outputs: Vec<(Option<&'b dyn Drawer>, &'c Path)>,
 }
 
 impl<'a, 'b, 'c, T> Layouter<'a, 'b, 'c, T>
@@ -48,11 +58,16 @@ where
             tree,
             drawer: None,
             file_name: None,
+            root: None,
+            node_limit: None,
+            options: LayoutOptions::default(),
+            outputs: Vec::new(),
         }
     }
 
     ///
     /// Sets the path of the output file on the layouter.
+    /// Its extension selects the output format unless a drawer is set with `with_drawer`.
     ///
     /// ```
     /// use id_tree_layout::{Layouter, Visualize};
@@ -74,25 +89,25 @@ where
     ///
     pub fn with_file_path(self, path: &'c std::path::Path) -> Self {
         Self {
-            tree: self.tree,
             file_name: Some(path),
-            drawer: self.drawer,
+            ..self
         }
     }
 
     ///
-    /// Sets a different drawer when you don't want to use the default svg-drawer.
-    /// If this method is not called the crate's own svg-drawer is used.
+    /// Sets a different drawer which is used regardless of the output file's extension.
+    /// If this method is not called the crate's drawer for the extension is used, e.g. the
+    /// svg-drawer for `svg` files.
     ///
     /// ```
-    /// use id_tree_layout::{Drawer, Layouter, PlacedTreeItem, Visualize};
+    /// use id_tree_layout::{Drawer, Embedding, Layouter, Visualize};
     /// use id_tree_layout::drawer::Result;
     /// use id_tree::{Tree, TreeBuilder};
     /// use std::path::Path;
     ///
     /// struct NilDrawer;
     /// impl Drawer for NilDrawer {
-    ///     fn draw(&self, _file_name: &Path, _embedding: &[PlacedTreeItem]) -> Result {
+    ///     fn draw(&self, _file_name: &Path, _embedding: &Embedding) -> Result {
     ///         Ok(())
     ///     }
     /// }
@@ -114,12 +129,81 @@ where
     ///
     pub fn with_drawer(self, drawer: &'b dyn Drawer) -> Self {
         Self {
-            tree: self.tree,
-            file_name: self.file_name,
             drawer: Some(drawer),
+            ..self
         }
     }
 
+    ///
+    /// Restricts the layout to the subtree starting at the given node.
+    /// If the node isn't a valid node of the tree `write` fails with
+    /// `LayouterError::InvalidTree`.
+    ///
+    pub fn with_root(self, root: NodeId) -> Self {
+        Self {
+            root: Some(root),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the maximum number of nodes to lay out. If the tree, or the subtree, has more nodes
+    /// `write` fails with `LayouterError::ResourceLimit` before anything is embedded or written.
+    ///
+    pub fn with_node_limit(self, limit: usize) -> Self {
+        Self {
+            node_limit: Some(limit),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the layout options used by the embedder and the drawer selected by the extension.
+    /// A drawer set with `with_drawer` needs to be configured on its own.
+    ///
+    /// ```
+    /// use id_tree_layout::{LayoutOptions, Layouter, Visualize};
+    /// use id_tree::{Tree, TreeBuilder};
+    /// use std::path::Path;
+    ///
+    /// struct MyNodeData(i32);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    ///
+    /// let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let layouter = Layouter::new(&tree)
+    ///     .with_options(LayoutOptions {
+    ///         level_gap: 4.0,
+    ///         ..Default::default()
+    ///     })
+    ///     .with_file_path(Path::new("test.svg"));
+    /// ```
+    ///
+    pub fn with_options(self, options: LayoutOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    ///
+    /// Adds an output file that is written by `write_all`, its extension selects the output
+    /// format.
+    ///
+    pub fn with_output(mut self, path: &'c Path) -> Self {
+        self.outputs.push((None, path));
+        self
+    }
+
+    ///
+    /// Adds an output file that is written by `write_all` with the given drawer, regardless of
+    /// the file's extension.
+    ///
+    pub fn with_drawer_output(mut self, drawer: &'b dyn Drawer, path: &'c Path) -> Self {
+        self.outputs.push((Some(drawer), path));
+        self
+    }
+
     ///
     /// When the layouter instance is fully configured this method invokes the necessary embedding
     /// functionality and uses the drawer which writes the result to the output file in its own
@@ -128,7 +212,6 @@ where
     /// ```
     /// use id_tree_layout::{Layouter, Visualize};
     /// use id_tree::{Tree, TreeBuilder};
-    /// use std::path::Path;
     ///
     /// struct MyNodeData(i32);
     ///
@@ -139,23 +222,167 @@ where
     ///
     ///
     /// let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// let file_name = std::env::temp_dir().join("tree.svg");
     /// Layouter::new(&tree)
-    ///     .with_file_path(Path::new("test.svg"))
+    ///     .with_file_path(&file_name)
     ///     .write().expect("Failed writing layout")
     /// ```
     ///
     pub fn write(&self) -> Result {
-        if self.file_name.is_none() {
-            Err(LayouterError::from_description(
-                "No output file name given - use Layouter::with_file_path.".to_string(),
-            ))
+        let file_name = self.file_name.ok_or(LayouterError::MissingConfiguration(
+            "output file name - use Layouter::with_file_path",
+        ))?;
+        let default_drawer;
+        let drawer = match self.drawer {
+            Some(drawer) => drawer,
+            None => {
+                default_drawer = drawer_for(file_name, self.options)?;
+                default_drawer.as_ref()
+            }
+        };
+        let embedding = embed_tree(self.tree, self.root.as_ref(), self.options, self.node_limit)?;
+        drawer.draw(file_name, &embedding)?;
+        Ok(())
+    }
+
+    ///
+    /// Embeds the tree once and writes it to all outputs, i.e. the file set with
+    /// `with_file_path`, if any, and the ones added with `with_output` or `with_drawer_output`.
+    ///
+    /// A failure of one output doesn't prevent the others from being written. The failed
+    /// outputs are returned together in `LayouterError::Outputs`. Failures that concern the
+    /// embedding, like an exceeded node limit, are returned immediately.
+    ///
+    /// ```
+    /// use id_tree_layout::{Layouter, LayouterError, Visualize};
+    /// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+    /// use std::path::Path;
+    ///
+    /// struct MyNodeData(i32);
+    ///
+    /// impl Visualize for MyNodeData {
+    ///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+    /// }
+    ///
+    /// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    /// tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    /// let svg = std::env::temp_dir().join("tree.svg");
+    /// let dot = std::env::temp_dir().join("tree.dot");
+    /// let bmp = std::env::temp_dir().join("tree.bmp");
+    /// let result = Layouter::new(&tree)
+    ///     .with_output(&svg)
+    ///     .with_output(&dot)
+    ///     .with_output(&bmp)
+    ///     .write_all();
+    /// match result {
+    ///     Err(LayouterError::Outputs(failures)) => assert_eq!(bmp, failures[0].0),
+    ///     _ => panic!("Expected the bitmap to fail"),
+    /// }
+    /// assert!(svg.exists() && dot.exists());
+    /// ```
+    ///
+    pub fn write_all(&self) -> Result {
+        let outputs = self
+            .file_name
+            .map(|file_name| (self.drawer, file_name))
+            .into_iter()
+            .chain(self.outputs.iter().cloned())
+            .collect::<Vec<(Option<&dyn Drawer>, &Path)>>();
+        if outputs.is_empty() {
+            return Err(LayouterError::MissingConfiguration(
+                "output file name - use Layouter::with_file_path or Layouter::with_output",
+            ));
+        }
+
+        let embedding = embed_tree(self.tree, self.root.as_ref(), self.options, self.node_limit)?;
+        let mut failures = Vec::new();
+        for (drawer, file_name) in outputs {
+            let default_drawer;
+            let drawer = match drawer {
+                Some(drawer) => drawer,
+                None => match drawer_for(file_name, self.options) {
+                    Ok(drawer) => {
+                        default_drawer = drawer;
+                        default_drawer.as_ref()
+                    }
+                    Err(err) => {
+                        failures.push((file_name.to_path_buf(), err));
+                        continue;
+                    }
+                },
+            };
+            if let Err(err) = drawer.draw(file_name, &embedding) {
+                failures.push((file_name.to_path_buf(), err.into()));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
         } else {
-            let embedding = Embedder::embed(self.tree);
-            let default_drawer = SvgDrawer::new();
-            let drawer = self.drawer.unwrap_or(&default_drawer);
-            drawer
-                .draw(self.file_name.unwrap(), &embedding)
-                .map_err(LayouterError::from_io_error)
+            Err(LayouterError::Outputs(failures))
         }
     }
 }
+
+///
+/// Selects the crate's drawer for the output format given by the file's extension, which is
+/// compared case-insensitively:
+/// * `svg` selects the `SvgDrawer`,
+/// * `dot` and `gv` the `DotDrawer`,
+/// * `txt` the `AsciiDrawer`,
+/// * `html` and `htm` the `HtmlDrawer`,
+/// * `json` the `JsonDrawer`, with the feature `serde_support` only.
+///
+/// Other extensions, as well as a missing one, are reported as
+/// `LayouterError::UnsupportedFormat`.
+///
+pub(crate) fn drawer_for(
+    file_name: &Path,
+    options: LayoutOptions,
+) -> layouter_error::Result<Box<dyn Drawer>> {
+    let extension = file_name
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "svg" => Ok(Box::new(SvgDrawer::new().with_options(options))),
+        "dot" | "gv" => Ok(Box::new(DotDrawer::new())),
+        "txt" => Ok(Box::new(AsciiDrawer::new())),
+        "html" | "htm" => Ok(Box::new(
+            HtmlDrawer::new().with_drawer(SvgDrawer::new().with_options(options)),
+        )),
+        #[cfg(feature = "serde_support")]
+        "json" => Ok(Box::new(JsonDrawer::new())),
+        _ => Err(LayouterError::UnsupportedFormat(extension)),
+    }
+}
+
+///
+/// Embeds the tree, or the subtree starting at `root`, after checking the node limit.
+/// This is the common part of `Layouter` and `Renderer`.
+///
+pub(crate) fn embed_tree<T: Visualize>(
+    tree: &Tree<T>,
+    root: Option<&NodeId>,
+    options: LayoutOptions,
+    node_limit: Option<usize>,
+) -> layouter_error::Result<Embedding> {
+    let root = root.or_else(|| tree.root_node_id());
+
+    if let (Some(limit), Some(root)) = (node_limit, root) {
+        let count = tree.traverse_pre_order_ids(root)?.count();
+        if count > limit {
+            return Err(LayouterError::ResourceLimit {
+                resource: "nodes",
+                limit,
+                actual: count,
+            });
+        }
+    }
+
+    let embedder = Embedder::new().with_options(options);
+    Ok(match root {
+        Some(root) => embedder.embed_subtree(tree, root)?,
+        None => embedder.embed_tree(tree),
+    })
+}
@@ -1,46 +1,111 @@
 //! The module with the Error type that is uses within the public API `Layouter`.
+use crate::drawer::DrawerError;
+use id_tree::NodeIdError;
 use std::fmt;
 use std::io::Error;
+use std::path::PathBuf;
 
+///
+/// The LayouterError type distinguishes the causes why the `Layouter` failed.
+/// New causes may be added in the future, thus matches need a wildcard arm.
+///
 #[derive(Debug)]
-pub struct LayouterError {
-    pub description: String,
-    pub io_error: Option<Error>,
+#[non_exhaustive]
+pub enum LayouterError {
+    /// A required setting of the layouter is missing, the value names the setting
+    MissingConfiguration(&'static str),
+    /// There is no drawer for the output format, the value is the file's extension
+    UnsupportedFormat(String),
+    /// Reading or writing a file failed
+    Io(Error),
+    /// The drawer failed for a reason specific to it
+    Drawer(Box<dyn std::error::Error + Send + Sync>),
+    /// The tree can't be laid out, e.g. because the root of the subtree is a stale `NodeId`
+    InvalidTree(NodeIdError),
+    /// The tree exceeds a configured limit
+    ResourceLimit {
+        /// The name of the limited resource
+        resource: &'static str,
+        /// The configured limit
+        limit: usize,
+        /// The amount the tree would need
+        actual: usize,
+    },
+    /// Writing some of several outputs failed, the value holds the failed files with the causes
+    Outputs(Vec<(PathBuf, LayouterError)>),
 }
 
 impl fmt::Display for LayouterError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(err) = &self.io_error {
-            write!(f, "{}", err)
-        } else {
-            write!(f, "{}", self.description)
+        match self {
+            LayouterError::MissingConfiguration(setting) => {
+                write!(f, "Missing configuration: {}", setting)
+            }
+            LayouterError::UnsupportedFormat(extension) if extension.is_empty() => write!(
+                f,
+                "Unsupported output format: the file name has no extension"
+            ),
+            LayouterError::UnsupportedFormat(extension) => write!(
+                f,
+                "Unsupported output format: no drawer for the extension '{}'",
+                extension
+            ),
+            LayouterError::Io(err) => write!(f, "{}", err),
+            LayouterError::Drawer(err) => write!(f, "Drawer failed: {}", err),
+            LayouterError::InvalidTree(err) => write!(f, "Invalid tree: {}", err),
+            LayouterError::ResourceLimit {
+                resource,
+                limit,
+                actual,
+            } => write!(
+                f,
+                "Resource limit exceeded: {} {} exceed the limit of {}",
+                actual, resource, limit
+            ),
+            LayouterError::Outputs(failures) => {
+                write!(f, "Writing {} output(s) failed", failures.len())?;
+                for (file_name, err) in failures {
+                    write!(f, "; {}: {}", file_name.display(), err)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 impl std::error::Error for LayouterError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        if let Some(err) = &self.io_error {
-            Some(err)
-        } else {
-            None
+        match self {
+            LayouterError::Io(err) => Some(err),
+            LayouterError::Drawer(err) => Some(err.as_ref()),
+            LayouterError::InvalidTree(err) => Some(err),
+            LayouterError::Outputs(failures) => failures
+                .first()
+                .map(|(_, err)| err as &(dyn std::error::Error + 'static)),
+            _ => None,
         }
     }
 }
 
-impl LayouterError {
-    pub fn from_description(description: String) -> Self {
-        Self {
-            description,
-            io_error: None,
-        }
+impl From<Error> for LayouterError {
+    fn from(io_error: Error) -> Self {
+        LayouterError::Io(io_error)
     }
-    pub fn from_io_error(io_error: Error) -> Self {
-        Self {
-            description: "IoError".to_string(),
-            io_error: Some(io_error),
+}
+
+impl From<DrawerError> for LayouterError {
+    fn from(drawer_error: DrawerError) -> Self {
+        match drawer_error {
+            DrawerError::Io(io_error) => LayouterError::Io(io_error),
+            DrawerError::Other(err) => LayouterError::Drawer(err),
         }
     }
 }
 
+impl From<NodeIdError> for LayouterError {
+    fn from(node_id_error: NodeIdError) -> Self {
+        LayouterError::InvalidTree(node_id_error)
+    }
+}
+
 pub type Result<T> = std::result::Result<T, LayouterError>;
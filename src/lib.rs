@@ -1,13 +1,47 @@
+pub use ascii_drawer::AsciiDrawer;
+pub use dot_drawer::DotDrawer;
 pub use drawer::Drawer;
-pub use embedder::{Embedder, Embedding, PlacedTreeItem};
+pub use embedder::{
+    BoundingBox, ChainCompression, Embedder, Embedding, ForestTree, PlacedTreeItem,
+};
+pub use embedding_error::EmbeddingError;
+pub use geometry::Geometry;
+pub use html_drawer::HtmlDrawer;
+pub use incremental_embedder::IncrementalEmbedder;
+#[cfg(feature = "serde_support")]
+pub use json_drawer::JsonDrawer;
+pub use layout_options::LayoutOptions;
 pub use layouter::{Layouter, Result};
 pub use layouter_error::LayouterError;
+pub use partition::{Partition, Partitioner, PlacedPartitionItem};
+pub use renderer::Renderer;
+pub use spatial_index::SpatialIndex;
 pub use svg_drawer::SvgDrawer;
+pub use svg_transition::SvgTransition;
+pub use treemap::{PlacedTreemapItem, Treemap, Treemapper};
 pub use visualize::Visualize;
 
+pub mod ascii_drawer;
+pub mod diff;
+pub mod dot_drawer;
 pub mod drawer;
 pub mod embedder;
+pub mod embedding_error;
+pub mod geometry;
+pub mod html_drawer;
+pub mod incremental_embedder;
+#[cfg(feature = "serde_support")]
+pub mod json_drawer;
+pub mod layout_options;
 pub mod layouter;
 pub mod layouter_error;
+pub mod partition;
+pub mod renderer;
+pub mod spatial_index;
 pub mod svg_drawer;
+mod svg_partition;
+pub mod svg_transition;
+pub mod theme;
+pub mod treemap;
 pub mod visualize;
+mod weights;
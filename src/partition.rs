@@ -0,0 +1,201 @@
+//! The module with the space-filling partition of a tree used by icicle and sunburst diagrams.
+
+use crate::visualize::Visualize;
+use crate::weights::{accumulate_weights, NodeValue};
+use id_tree::{NodeId, Tree};
+
+///
+/// The PlacedPartitionItem is the information of one single tree node within a `Partition`.
+///
+/// Each node occupies a span of the unit interval on the row given by its depth. The spans of a
+/// node's children lie within the node's own span, ordered from left to right.
+/// An icicle diagram scales the unit interval to the diagram's width, a sunburst diagram to the
+/// full circle.
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlacedPartitionItem {
+    /// The nodes depth, root has depth 0
+    pub depth: usize,
+    /// The start of the node's span within the unit interval
+    pub start: f64,
+    /// The length of the node's span within the unit interval
+    pub extent: f64,
+    /// The total weight of the node, i.e. its own weight plus the total weights of its children
+    pub weight: f64,
+    /// The text representation of the nodes data - created by the `Visualize` trait's implementation
+    pub text: String,
+    /// The *emphasize* property obtained from the `Visualize` trait
+    pub is_emphasized: bool,
+    /// The parent's `ord`, if there is one
+    pub parent: Option<usize>,
+    /// A unique number reflecting the topological post-ordering of the nodes in the tree
+    pub ord: usize,
+    /// The id of the tree node
+    pub node_id: Option<NodeId>,
+}
+
+///
+/// The Partition is the result of the `Partitioner`. Its items are ordered by their `ord`.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Partition {
+    /// The items ordered by `ord`
+    items: Vec<PlacedPartitionItem>,
+    /// The number of rows
+    depth: usize,
+}
+
+impl Partition {
+    ///
+    /// Returns the number of rows, i.e. the depth of the deepest item plus one.
+    ///
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+impl std::ops::Deref for Partition {
+    type Target = [PlacedPartitionItem];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<'a> IntoIterator for &'a Partition {
+    type Item = &'a PlacedPartitionItem;
+    type IntoIter = std::slice::Iter<'a, PlacedPartitionItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+///
+/// The Partitioner type arranges the nodes of a tree as nested spans whose lengths are
+/// proportional to the nodes' weights.
+///
+/// By default the weights are obtained from the `Visualize` trait's `weight` method. Nodes
+/// without a weight count 1 if they are leaves and 0 otherwise, thus without any weights the span
+/// of a node is proportional to the number of leaves in its subtree. Use `with_weights` to supply
+/// the weights by a function instead.
+///
+/// ```
+/// use id_tree_layout::{Partitioner, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+/// tree.insert(Node::new(MyNodeData(1)), UnderNode(&root)).unwrap();
+/// tree.insert(Node::new(MyNodeData(3)), UnderNode(&root)).unwrap();
+///
+/// let partition = Partitioner::new()
+///     .with_weights(|d: &MyNodeData| d.0 as f64)
+///     .partition(&tree);
+/// assert_eq!(4.0, partition[2].weight);
+/// assert_eq!(0.25, partition[0].extent);
+/// assert_eq!(0.25, partition[1].start);
+/// ```
+///
+pub struct Partitioner<T>
+where
+    T: Visualize,
+{
+    weight: Option<NodeValue<T>>,
+}
+
+impl<T> Default for Partitioner<T>
+where
+    T: Visualize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Partitioner<T>
+where
+    T: Visualize,
+{
+    ///
+    /// Creates a new Partitioner that obtains the weights from the `Visualize` trait.
+    ///
+    pub fn new() -> Self {
+        Self { weight: None }
+    }
+
+    ///
+    /// Sets the function that returns the own weight of a node's data. The total weight of a
+    /// node is its own weight plus the total weights of its children, so the own weight of an
+    /// internal node remains as an empty gap at the end of its span.
+    /// Negative weights are treated as zero.
+    ///
+    pub fn with_weights<F>(self, weight: F) -> Self
+    where
+        F: Fn(&T) -> f64 + 'static,
+    {
+        Self {
+            weight: Some(Box::new(weight)),
+        }
+    }
+
+    ///
+    /// This method creates the partition of the given tree.
+    /// If the total weight of a node is zero its children get spans of length zero.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn partition(&self, tree: &Tree<T>) -> Partition {
+        let mut items: Vec<PlacedPartitionItem> = Vec::new();
+        let mut children: Vec<Vec<usize>> = Vec::new();
+        for (ord, node) in accumulate_weights(tree, self.weight.as_ref())
+            .into_iter()
+            .enumerate()
+        {
+            let data = tree.get(&node.node_id).unwrap().data();
+            items.push(PlacedPartitionItem {
+                weight: node.weight,
+                text: data.visualize(),
+                is_emphasized: data.emphasize(),
+                parent: node.parent,
+                ord,
+                node_id: Some(node.node_id),
+                ..Default::default()
+            });
+            children.push(node.children);
+        }
+
+        let mut depth = 0;
+        if let Some(root) = items.last_mut() {
+            root.extent = 1.0;
+        }
+        // Parents always have a greater `ord` than their children, so we visit them first
+        for ord in (0..items.len()).rev() {
+            let parent = &items[ord];
+            let (child_depth, mut start, extent, weight) =
+                (parent.depth + 1, parent.start, parent.extent, parent.weight);
+            depth = std::cmp::max(depth, parent.depth + 1);
+            for child in &children[ord] {
+                let item = &mut items[*child];
+                item.depth = child_depth;
+                item.start = start;
+                item.extent = if weight > 0.0 {
+                    extent * item.weight / weight
+                } else {
+                    0.0
+                };
+                start += item.extent;
+            }
+        }
+
+        Partition { items, depth }
+    }
+}
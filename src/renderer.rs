@@ -0,0 +1,129 @@
+//! The module with the owned and reusable counterpart of the `Layouter`.
+use crate::layouter::{drawer_for, embed_tree, Result};
+use crate::{Drawer, LayoutOptions, Visualize};
+use id_tree::{NodeId, Tree};
+use std::path::Path;
+
+///
+/// The Renderer type holds the same configuration as the `Layouter`, but owns it and doesn't
+/// borrow the tree. It is created once and renders any number of trees. Because it is `Send`
+/// and `Sync` it can be shared between threads, e.g. in a batch job.
+///
+/// ```
+/// use id_tree_layout::{Renderer, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let renderer = Renderer::new().with_node_limit(1000);
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+/// renderer.render(&tree, std::env::temp_dir().join("tree.svg")).expect("Failed writing layout");
+/// ```
+///
+#[derive(Default)]
+pub struct Renderer {
+    drawer: Option<Box<dyn Drawer + Send + Sync>>,
+    options: LayoutOptions,
+    node_limit: Option<usize>,
+}
+
+impl Renderer {
+    ///
+    /// Creates a new Renderer that selects the crate's drawer by the extension of the target file
+    /// and uses the default layout options.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets a different drawer which is used regardless of the target file's extension.
+    ///
+    pub fn with_drawer<D>(self, drawer: D) -> Self
+    where
+        D: Drawer + Send + Sync + 'static,
+    {
+        Self {
+            drawer: Some(Box::new(drawer)),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the layout options used by the embedder and the drawer selected by the extension.
+    /// A drawer set with `with_drawer` needs to be configured on its own.
+    ///
+    pub fn with_options(self, options: LayoutOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    ///
+    /// Sets the maximum number of nodes to lay out. If a tree, or a subtree, has more nodes
+    /// rendering it fails with `LayouterError::ResourceLimit` before anything is embedded or
+    /// written.
+    ///
+    pub fn with_node_limit(self, limit: usize) -> Self {
+        Self {
+            node_limit: Some(limit),
+            ..self
+        }
+    }
+
+    ///
+    /// Embeds the tree and uses the drawer to write the result to the target file.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn render<T, P>(&self, tree: &Tree<T>, target: P) -> Result
+    where
+        T: Visualize,
+        P: AsRef<Path>,
+    {
+        self.render_from(tree, None, target.as_ref())
+    }
+
+    ///
+    /// Embeds the subtree starting at the given node and uses the drawer to write the result to
+    /// the target file.
+    /// If the node isn't a valid node of the tree rendering fails with
+    /// `LayouterError::InvalidTree`.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn render_subtree<T, P>(&self, tree: &Tree<T>, root: &NodeId, target: P) -> Result
+    where
+        T: Visualize,
+        P: AsRef<Path>,
+    {
+        self.render_from(tree, Some(root), target.as_ref())
+    }
+
+    fn render_from<T: Visualize>(
+        &self,
+        tree: &Tree<T>,
+        root: Option<&NodeId>,
+        target: &Path,
+    ) -> Result {
+        let default_drawer;
+        let drawer: &dyn Drawer = match &self.drawer {
+            Some(drawer) => drawer.as_ref(),
+            None => {
+                default_drawer = drawer_for(target, self.options)?;
+                default_drawer.as_ref()
+            }
+        };
+        let embedding = embed_tree(tree, root, self.options, self.node_limit)?;
+        drawer.draw(target, &embedding)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+//! The module with the `SpatialIndex` type used for hit testing.
+
+use crate::embedder::Embedding;
+use crate::geometry::{Geometry, Point, Rect};
+
+///
+/// All nodes of one level sorted by their rectangle's left border.
+///
+#[derive(Debug, Clone, Default)]
+struct Row {
+    /// The upper border of the row, i.e. the minimum over its rectangles' upper borders
+    top: f64,
+    /// The lower border of the row, i.e. the maximum over its rectangles' lower borders
+    bottom: f64,
+    /// The rectangles with the `ord`s of their nodes
+    items: Vec<(Rect, usize)>,
+    /// The maximum right border over all rectangles up to the same index in `items`
+    max_right: Vec<f64>,
+}
+
+impl Row {
+    /// Calls `f` for all items whose horizontal extent intersects the range from `left` to
+    /// `right`, from the greatest left border to the smallest. Only items whose left border is
+    /// within the range or that overlap the range from the left are visited.
+    fn visit_range<F: FnMut(&Rect, usize)>(&self, left: f64, right: f64, mut f: F) {
+        // The number of items starting left of the range's right border
+        let end = self.items.partition_point(|(rect, _)| rect.x <= right);
+        for index in (0..end).rev() {
+            if self.max_right[index] < left {
+                break;
+            }
+            let (rect, ord) = &self.items[index];
+            if rect.right() >= left {
+                f(rect, *ord);
+            }
+        }
+    }
+}
+
+///
+/// The `SpatialIndex` type answers the question which nodes are located at a point or within a
+/// rectangle.
+///
+/// Build it once per layout, either in logical coordinates with `Embedding::spatial_index` or in
+/// the coordinates of a concrete geometry with `Geometry::spatial_index`. Use
+/// `SvgDrawer::geometry` to obtain the geometry with the coordinates of the `SvgDrawer`'s output.
+///
+/// In logical coordinates the area of a node spans its `x_extent` horizontally centered at its
+/// `x_center` and one level vertically centered at its `y_order`.
+///
+/// # Complexity
+///
+/// Building the index is of time complexity class O(n log n). Each query checks the vertical
+/// range of every level and runs a binary search in each level it overlaps. Thus a point query
+/// is of time complexity class O(d + log n) if the levels don't overlap vertically, as in the
+/// layouts of the crate's embedders, and O(d log n) otherwise. A rectangle query, which sorts
+/// the nodes it finds, is of time complexity class O(d log n + k log k) where d is the number
+/// of levels and k the number of nodes found. Overlapping areas within a level, e.g. of nodes with width hints, may add the number
+/// of nodes of the level that start left of the query.
+///
+#[derive(Debug, Clone, Default)]
+pub struct SpatialIndex {
+    /// The rows, one for each level
+    rows: Vec<Row>,
+}
+
+impl SpatialIndex {
+    /// Creates the index from the nodes' rectangles, each given with the node's `ord` and level.
+    fn new<I: Iterator<Item = (Rect, usize, usize)>>(nodes: I) -> Self {
+        let mut rows: Vec<Row> = Vec::new();
+        for (rect, ord, level) in nodes {
+            if rows.len() <= level {
+                rows.resize(level + 1, Row::default());
+            }
+            let row = &mut rows[level];
+            if row.items.is_empty() {
+                row.top = rect.y;
+                row.bottom = rect.bottom();
+            } else {
+                row.top = row.top.min(rect.y);
+                row.bottom = row.bottom.max(rect.bottom());
+            }
+            row.items.push((rect, ord));
+        }
+        for row in &mut rows {
+            row.items.sort_by(|(r1, _), (r2, _)| r1.x.total_cmp(&r2.x));
+            row.max_right = row
+                .items
+                .iter()
+                .scan(f64::NEG_INFINITY, |max, (rect, _)| {
+                    *max = max.max(rect.right());
+                    Some(*max)
+                })
+                .collect();
+        }
+        Self { rows }
+    }
+
+    /// Creates the index in logical coordinates.
+    pub(crate) fn from_embedding(embedding: &Embedding) -> Self {
+        Self::new(embedding.iter().map(|item| {
+            (
+                Rect {
+                    x: item.x_center as f64 - item.x_extent as f64 / 2.0,
+                    y: item.y_order as f64 - 0.5,
+                    width: item.x_extent as f64,
+                    height: 1.0,
+                },
+                item.ord,
+                item.y_order,
+            )
+        }))
+    }
+
+    /// Creates the index in the coordinates of the given geometry.
+    pub(crate) fn from_geometry(geometry: &Geometry) -> Self {
+        Self::new(
+            geometry
+                .nodes()
+                .iter()
+                .map(|node| (node.rect, node.ord, node.y_order)),
+        )
+    }
+
+    ///
+    /// Returns the `ord` of the node whose area contains the given point.
+    /// If the areas of several nodes contain the point the one on the deepest level, i.e. with
+    /// the greatest `y_order`, and within this level the one with the greatest left border is
+    /// returned.
+    ///
+    pub fn node_at(&self, point: Point) -> Option<usize> {
+        let mut result = None;
+        for row in self.rows.iter().rev() {
+            if point.y < row.top || point.y > row.bottom {
+                continue;
+            }
+            row.visit_range(point.x, point.x, |rect, ord| {
+                if result.is_none() && rect.contains(point) {
+                    result = Some(ord);
+                }
+            });
+            if result.is_some() {
+                break;
+            }
+        }
+        result
+    }
+
+    ///
+    /// Returns the `ord`s of all nodes whose areas intersect the given rectangle in ascending
+    /// order.
+    ///
+    pub fn nodes_in(&self, rect: &Rect) -> Vec<usize> {
+        let mut result = Vec::new();
+        for row in &self.rows {
+            if rect.bottom() < row.top || rect.y > row.bottom {
+                continue;
+            }
+            row.visit_range(rect.x, rect.right(), |r, ord| {
+                if r.intersects(rect) {
+                    result.push(ord);
+                }
+            });
+        }
+        result.sort_unstable();
+        result
+    }
+}
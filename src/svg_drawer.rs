@@ -1,150 +1,628 @@
 //! The module with the crate's default drawer.
 
-use crate::Drawer;
+use crate::drawer::{self, Drawer};
+use std::io::{BufWriter, Write};
 use std::path::Path;
+use std::sync::Arc;
 use xml_writer::XmlWriter;
 
 use std::fs::File;
 
-use super::embedder::PlacedTreeItem;
+use super::diff::DiffStatus;
+use super::embedder::{Caption, Embedding, PlacedTreeItem};
+use super::geometry::{Edge, Geometry, MonospaceMeasurer, NodeGeometry, Rect, Size, Spacing};
+use super::layout_options::LayoutOptions;
+use super::theme::Theme;
 
 pub type Result = std::io::Result<()>;
 
-const X_MARGIN: f32 = 10.0;
-const Y_MARGIN: f32 = 25.0;
-const Y_FACTOR: f32 = 3.5;
-const FONT_X_SIZE: f32 = 10.0;
-const FONT_Y_SIZE: f32 = 10.0;
+const FONT_X_SIZE: f64 = 10.0;
+pub(crate) const FONT_Y_SIZE: f64 = 10.0;
+
+const FONT_FAMILY: &str = "Courier";
+
+/// The class of the root element, which scopes the rules of the embedded style sheet to the Svg
+const ROOT_CLASS: &str = "id-tree-layout";
+
+/// The maximum number of labels in the path of a node's description, longer paths are elided
+/// in the middle to keep the size of the Svg linear in the number of nodes
+const MAX_PATH_LABELS: usize = 12;
+
+/// A function that derives a string from an item, e.g. its id
+type ItemString = Arc<dyn Fn(&PlacedTreeItem) -> String + Send + Sync>;
 
 ///
 /// The `SvgDrawer` type provides the transformation of the embedding information into the Svg
 /// format.
 ///
-#[derive(Debug, Default)]
-pub struct SvgDrawer;
+/// Each node is written as a group with the id `node-` followed by its `ord` and the classes
+/// * `node`,
+/// * `depth-` followed by its level, e.g. `depth-0` for the root,
+/// * `leaf` if it has no children,
+/// * `emphasized` if it is emphasized.
+///
+/// The group contains the edge from the node's parent, with the class `edge`, the node's text
+/// and the groups of its children. The fonts and colors are presentation attributes, thus any
+/// style sheet, the one set with `with_style_sheet` or the one of the page the Svg is embedded
+/// in, can override them.
+///
+/// For assistive technologies the Svg has the ARIA role `tree` and each node's group the role
+/// `treeitem` with its `aria-level`, the groups of its children are wrapped into a group with
+/// the role `group`. Each node has a `title` with its full label and a `desc` with the path of
+/// labels from its root, long paths are elided in the middle. The nodes are written in
+/// pre-order, thus screen readers read them from the root downwards, and the caption of each
+/// tree of a forest precedes the tree.
+///
+/// A node's tooltip, see `Visualize::tooltip`, is appended to its title, which browsers show
+/// when the pointer hovers over the node. The text of a node with a URL, see `Visualize::url`,
+/// is wrapped into a hyperlink element `a` with the Svg 2 attribute `href`.
+///
+#[derive(Clone, Default)]
+pub struct SvgDrawer {
+    options: LayoutOptions,
+    theme: Theme,
+    dark_theme: Option<Theme>,
+    node_id: Option<ItemString>,
+    style_sheet: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+}
+
+impl std::fmt::Debug for SvgDrawer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SvgDrawer")
+            .field("options", &self.options)
+            .field("theme", &self.theme)
+            .field("dark_theme", &self.dark_theme)
+            .field("style_sheet", &self.style_sheet)
+            .field("title", &self.title)
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
 
 impl SvgDrawer {
     /// Method to create a fresh instance of the `SvgDrawer` type.
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
-    fn scale_y(y: usize) -> f32 {
-        y as f32 * FONT_Y_SIZE * Y_FACTOR + Y_MARGIN
+    ///
+    /// Sets the layout options. The drawer applies the level gap, the margins and the
+    /// orientation, the horizontal gaps and the alignment are up to the `Embedder`.
+    /// The space-filling diagrams don't use the options.
+    ///
+    pub fn with_options(self, options: LayoutOptions) -> Self {
+        Self { options, ..self }
     }
 
-    fn scale_x(x: usize) -> f32 {
-        x as f32 * FONT_X_SIZE + X_MARGIN
+    ///
+    /// Sets the theme the drawer paints with. The default is the light theme.
+    ///
+    /// ```
+    /// use id_tree_layout::SvgDrawer;
+    /// use id_tree_layout::theme::Theme;
+    ///
+    /// let drawer = SvgDrawer::new().with_theme(Theme::high_contrast());
+    /// ```
+    ///
+    pub fn with_theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
     }
 
-    fn measure_string(str: &str) -> f32 {
-        str.len() as f32 * FONT_X_SIZE
+    ///
+    /// Sets a theme for viewers that prefer a dark color scheme. The drawer then embeds a style
+    /// sheet with a `prefers-color-scheme` media query into the Svg, so that it adapts to the
+    /// page it is shown on. The style sheet covers the background, the texts, the edges and the
+    /// colors of diffs, the fill colors of the space-filling diagrams are not adapted. Its rules
+    /// are scoped to the Svg's root element with the class `id-tree-layout`, thus an inlined Svg
+    /// leaves the rest of the page alone. Colors with characters that don't belong into a color
+    /// value, e.g. `;` or `}`, are left out of the style sheet.
+    ///
+    pub fn with_dark_theme(self, dark_theme: Theme) -> Self {
+        Self {
+            dark_theme: Some(dark_theme),
+            ..self
+        }
     }
-}
 
-///
-/// The concrete implementation of the `Drawer` trait for `SvgDrawer`.
-///
-impl Drawer for SvgDrawer {
     ///
-    /// The concrete implementation of the `Drawer::draw` trait method.
-    /// The realization is as it is - with no way to configure for instance the font used.
-    /// This decision was mode for the sake of simplicity.
+    /// Sets the function that derives the id of each node's group from the node's item, e.g.
+    /// from its text or a key of the node's data. The ids need to be unique within the
+    /// document the Svg ends up in.
     ///
-    /// Anyway it should be easy to provide ones own Drawer implementation that fits the concrete
-    /// use case better.
-    /// When using the Layouter API you can set the Drawer instance by calling the `with_drawer`
-    /// method.
+    /// ```
+    /// use id_tree_layout::SvgDrawer;
     ///
-    /// # Panics
+    /// let drawer = SvgDrawer::new().with_node_ids(|item| format!("parse-tree-{}", item.ord));
+    /// ```
     ///
-    /// The method should not panic. If you encounter a panic this should be originated from
-    /// bugs in coding. Please report such panics.
+    pub fn with_node_ids<F>(self, id: F) -> Self
+    where
+        F: Fn(&PlacedTreeItem) -> String + Send + Sync + 'static,
+    {
+        Self {
+            node_id: Some(Arc::new(id)),
+            ..self
+        }
+    }
+
     ///
-    /// # Complexity
+    /// Sets a style sheet that is embedded into the Svg, e.g. to style the nodes by their
+    /// classes. Its rules take precedence over the ones of the dark theme.
     ///
-    /// The algorithm is of time complexity class O(n).
+    /// ```
+    /// use id_tree_layout::SvgDrawer;
     ///
-    fn draw(&self, file_name: &Path, embedding: &[PlacedTreeItem]) -> Result {
-        let file = File::create(file_name)?;
-        let mut xml = XmlWriter::new(file);
+    /// let drawer = SvgDrawer::new()
+    ///     .with_style_sheet(".leaf text { fill: darkgreen } .depth-0 text { font-size: 14px }");
+    /// ```
+    ///
+    pub fn with_style_sheet<S: Into<String>>(self, style_sheet: S) -> Self {
+        Self {
+            style_sheet: Some(style_sheet.into()),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the title of the Svg, which assistive technologies announce as its name and browsers
+    /// show as a tooltip.
+    ///
+    /// ```
+    /// use id_tree_layout::SvgDrawer;
+    ///
+    /// let drawer = SvgDrawer::new()
+    ///     .with_title("Parse tree of 'x = 2 * y'")
+    ///     .with_description("The assignment's right side is a product of two factors.");
+    /// ```
+    ///
+    pub fn with_title<S: Into<String>>(self, title: S) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the description of the Svg, e.g. a summary of what the tree shows.
+    ///
+    pub fn with_description<S: Into<String>>(self, description: S) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    ///
+    /// Creates the geometry of the given embedding exactly as the `SvgDrawer` paints it.
+    /// Use it for instance to relate coordinates within the resulting Svg to the nodes.
+    ///
+    pub fn geometry(&self, embedding: &Embedding) -> Geometry {
+        let measurer = MonospaceMeasurer {
+            char_width: FONT_X_SIZE,
+            line_height: FONT_Y_SIZE,
+        };
+        let spacing = Spacing {
+            unit_width: FONT_X_SIZE,
+            level_height: FONT_Y_SIZE * (1.0 + self.options.level_gap),
+            margin_x: self.options.margin_x,
+            margin_y: self.options.margin_y,
+            edge_gap: FONT_Y_SIZE,
+            orientation: self.options.orientation,
+        };
+        Geometry::new(embedding, &measurer, &spacing)
+    }
+
+    /// The theme the drawer paints with.
+    pub(crate) fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// The title of the Svg, if there is one.
+    pub(crate) fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
 
+    /// Creates the file and the writer of a document and writes the XML declaration.
+    pub(crate) fn create_document<'a>(
+        file_name: &Path,
+    ) -> std::io::Result<XmlWriter<'a, BufWriter<File>>> {
+        let mut xml = Self::create_writer(BufWriter::new(File::create(file_name)?));
         xml.dtd("UTF-8")?;
+        Ok(xml)
+    }
+
+    /// Creates the writer of a document. The output is not indented, the indentation of nested
+    /// groups would grow with the depth of the tree.
+    pub(crate) fn create_writer<'a, W: Write>(writer: W) -> XmlWriter<'a, W> {
+        let mut xml = XmlWriter::new(writer);
+        xml.pretty = false;
+        xml
+    }
+
+    /// Writes the document's header with the given ARIA role, the title, the description and
+    /// the background. The texts inherit their color from the document.
+    pub(crate) fn begin_document<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        size: Size,
+        role: &str,
+    ) -> Result {
         xml.begin_elem("svg")?;
         xml.attr("xmlns", "http://www.w3.org/2000/svg")?;
         xml.attr("version", "1.1")?;
+        xml.attr("class", ROOT_CLASS)?;
         xml.attr("lang", "en")?;
+        xml.attr("width", format!("{}", size.width).as_str())?;
+        xml.attr("height", format!("{}", size.height).as_str())?;
+        xml.attr_esc("fill", self.theme.text.as_str())?;
+        xml.attr("role", role)?;
+        if let Some(title) = &self.title {
+            Self::write_text_elem(xml, "title", title)?;
+        }
+        if let Some(description) = &self.description {
+            Self::write_text_elem(xml, "desc", description)?;
+        }
+        self.write_style_sheet(xml)?;
 
-        const STRING_FONT: &str = "font-family: 'Courier'; font-style: normal";
-        const EMPHASIZE_FONT: &str =
-            "font-family: 'Courier'; font-weight: bold; font-style: normal";
+        // Draw on a background rectangle to be visible on any background of the viewer.
+        xml.begin_elem("rect")?;
+        xml.attr("class", "background")?;
+        xml.attr("x", "0")?;
+        xml.attr("y", "0")?;
+        xml.attr("width", format!("{}", size.width).as_str())?;
+        xml.attr("height", format!("{}", size.height).as_str())?;
+        xml.attr_esc("fill", self.theme.background.as_str())?;
+        xml.attr("aria-hidden", "true")?;
+        xml.end_elem()
+    }
 
-        let tree_depth = embedding
-            .iter()
-            .fold(0, |acc, e| if e.y_order > acc { e.y_order } else { acc });
-        let tree_width = embedding.iter().fold(0, |acc, e| {
-            if e.x_extent_children > acc {
-                e.x_extent_children
-            } else {
-                acc
-            }
+    /// Writes an element that contains only the given text.
+    fn write_text_elem<W: Write>(xml: &mut XmlWriter<W>, name: &'static str, text: &str) -> Result {
+        xml.begin_elem(name)?;
+        xml.text(text)?;
+        xml.end_elem()
+    }
+
+    /// Writes the style sheet with the rules that switch to the dark theme, if there is one,
+    /// followed by the user's style sheet, if there is one. The rules of the dark theme are
+    /// scoped to the Svg by its root class, thus they don't restyle the page it is inlined into,
+    /// and colors that could break out of their declaration are left out.
+    fn write_style_sheet<W: Write>(&self, xml: &mut XmlWriter<W>) -> Result {
+        let color_scheme = self.dark_theme.as_ref().map(|dark| {
+            let rules = [
+                ("", "fill", &dark.text),
+                (" rect.background", "fill", &dark.background),
+                (" line", "stroke", &dark.line),
+                (" text.inserted", "fill", &dark.inserted),
+                (" text.deleted", "fill", &dark.deleted),
+                (" text.moved", "fill", &dark.moved),
+                (" text.relabelled", "fill", &dark.relabelled),
+            ];
+            let rules = rules
+                .iter()
+                .filter(|(_, _, color)| Self::is_css_value(color))
+                .map(|(selector, property, color)| {
+                    format!(
+                        "svg.{}{} {{ {}: {} }}",
+                        ROOT_CLASS, selector, property, color
+                    )
+                })
+                .collect::<Vec<String>>();
+            format!(
+                "@media (prefers-color-scheme: dark) {{ {} }}",
+                rules.join(" ")
+            )
         });
+        let style_sheet = color_scheme
+            .iter()
+            .chain(self.style_sheet.iter())
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+        if style_sheet.is_empty() {
+            return Ok(());
+        }
+        xml.begin_elem("style")?;
+        xml.text(style_sheet.join("\n").as_str())?;
+        xml.end_elem()
+    }
 
-        let img_width = Self::scale_x(tree_width);
-        let img_height = Self::scale_y(tree_depth + 1);
+    /// Returns whether a color can be used as the value of a declaration, i.e. it is not empty and
+    /// consists only of the characters of color names, hex colors and color functions.
+    fn is_css_value(color: &str) -> bool {
+        !color.trim().is_empty()
+            && color
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "#(),.%/ -".contains(c))
+    }
 
-        xml.attr("width", format!("{}", img_width).as_str())?;
-        xml.attr("height", format!("{}", img_height).as_str())?;
+    /// Writes the font attributes of a text element.
+    pub(crate) fn write_font<W: Write>(
+        xml: &mut XmlWriter<W>,
+        is_emphasized: bool,
+        is_italic: bool,
+    ) -> Result {
+        xml.attr("font-family", FONT_FAMILY)?;
+        if is_emphasized {
+            xml.attr("font-weight", "bold")?;
+        }
+        xml.attr("font-style", if is_italic { "italic" } else { "normal" })
+    }
 
-        // Draw on a white rectangle to be visible also on black backgrounds.
-        xml.begin_elem("rect")?;
-        xml.attr("x", "0")?;
-        xml.attr("y", "0")?;
-        xml.attr("width", format!("{}", img_width).as_str())?;
-        xml.attr("height", format!("{}", img_height).as_str())?;
-        xml.attr("fill", "white")?;
+    /// Closes the document and flushes the writer.
+    pub(crate) fn end_document<W: Write>(mut xml: XmlWriter<W>) -> Result {
         xml.end_elem()?;
+        xml.close()?;
+        xml.flush()
+    }
 
-        for data in embedding {
-            let font = if data.is_emphasized {
-                EMPHASIZE_FONT
-            } else {
-                STRING_FONT
-            };
-            let szx = Self::measure_string(&data.text);
-            let x = Self::scale_x(data.x_center) - szx / 2.0;
-            let y = Self::scale_y(data.y_order);
-            xml.begin_elem("text")?;
-            xml.attr("x", format!("{}", x).as_str())?;
-            xml.attr("y", format!("{}", y).as_str())?;
-            xml.attr("style", font)?;
-            xml.text(data.text.as_str())?;
-            xml.end_elem()?;
+    /// Writes the captions of a forest.
+    pub(crate) fn write_captions<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        embedding: &Embedding,
+        geometry: &Geometry,
+    ) -> Result {
+        for (caption, rect) in embedding.captions().iter().zip(geometry.captions()) {
+            Self::write_caption(xml, caption, rect)?;
+        }
+        Ok(())
+    }
 
-            if let Some(parent_index) = data.parent {
-                let parent_data = embedding.iter().find(|e| e.ord == parent_index).unwrap();
+    /// Writes the caption of one tree of a forest.
+    fn write_caption<W: Write>(xml: &mut XmlWriter<W>, caption: &Caption, rect: &Rect) -> Result {
+        xml.begin_elem("text")?;
+        xml.attr("x", format!("{}", rect.x).as_str())?;
+        xml.attr("y", format!("{}", rect.y + FONT_Y_SIZE).as_str())?;
+        Self::write_font(xml, false, true)?;
+        xml.text(caption.text.as_str())?;
+        xml.end_elem()
+    }
 
-                // Draw a line from the nodes parent down to this node
-                xml.begin_elem("line")?;
+    /// Writes the text element of one node with the given status of a diff.
+    pub(crate) fn write_text<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        data: &PlacedTreeItem,
+        node: &NodeGeometry,
+        diff_status: Option<DiffStatus>,
+    ) -> Result {
+        let diff_status = diff_status.filter(|status| *status != DiffStatus::Unchanged);
+        let x = node.rect.x;
+        // The text's baseline is at the bottom of its first line
+        let y = node.rect.y + FONT_Y_SIZE;
+        xml.begin_elem("text")?;
+        if let Some(status) = diff_status {
+            xml.attr("class", Self::diff_class(status))?;
+        }
+        xml.attr("x", format!("{}", x).as_str())?;
+        xml.attr("y", format!("{}", y).as_str())?;
+        Self::write_font(
+            xml,
+            data.is_emphasized,
+            diff_status == Some(DiffStatus::Moved),
+        )?;
+        if let Some(status) = diff_status {
+            xml.attr_esc("fill", self.diff_color(status))?;
+            if status == DiffStatus::Deleted {
+                xml.attr("text-decoration", "line-through")?;
+            }
+        }
+        if data.text.contains('\n') {
+            // Multi-line texts, e.g. from stacked unary chains, are written line by line
+            for (i, line) in data.text.split('\n').enumerate() {
+                xml.begin_elem("tspan")?;
+                xml.attr("x", format!("{}", x).as_str())?;
                 xml.attr(
-                    "x1",
-                    format!("{}", (Self::scale_x(parent_data.x_center))).as_str(),
+                    "dy",
+                    if i == 0 { 0.0 } else { FONT_Y_SIZE }.to_string().as_str(),
                 )?;
-                xml.attr(
-                    "y1",
-                    format!("{}", (Self::scale_y(parent_data.y_order) + FONT_Y_SIZE)).as_str(),
+                xml.text(line)?;
+                xml.end_elem()?;
+            }
+        } else {
+            xml.text(data.text.as_str())?;
+        }
+        xml.end_elem()
+    }
+
+    /// Begins the line element of an edge, the caller needs to end it.
+    pub(crate) fn begin_line<W: Write>(&self, xml: &mut XmlWriter<W>, edge: &Edge) -> Result {
+        xml.begin_elem("line")?;
+        xml.attr("x1", format!("{}", edge.from.x).as_str())?;
+        xml.attr("y1", format!("{}", edge.from.y).as_str())?;
+        xml.attr("x2", format!("{}", edge.to.x).as_str())?;
+        xml.attr("y2", format!("{}", edge.to.y).as_str())?;
+        xml.attr_esc("stroke", self.theme.line.as_str())
+    }
+
+    /// Writes the Svg element of the embedding without ending it.
+    pub(crate) fn write_svg<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        embedding: &Embedding,
+    ) -> Result {
+        let geometry = self.geometry(embedding);
+        self.begin_document(xml, geometry.size(), "tree")?;
+
+        // Each caption is written before the tree it is placed over, that is before the first
+        // tree that reaches beyond the caption's center.
+        let mut captions = embedding
+            .captions()
+            .iter()
+            .zip(geometry.captions())
+            .peekable();
+        for root in embedding.roots() {
+            let bounding_box = embedding.subtree_bounding_box(root.ord);
+            while let Some((caption, rect)) = captions
+                .next_if(|(caption, _)| caption.x_center < bounding_box.x + bounding_box.width)
+            {
+                Self::write_caption(xml, caption, rect)?;
+            }
+            self.write_tree(xml, embedding, &geometry, root.ord)?;
+        }
+        for (caption, rect) in captions {
+            Self::write_caption(xml, caption, rect)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the tree starting at the given root. The nodes are written in pre-order, each
+    /// node's group is ended after its children's. An explicit stack is used to support trees
+    /// of any depth.
+    fn write_tree<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        embedding: &Embedding,
+        geometry: &Geometry,
+        root: usize,
+    ) -> Result {
+        let mut stack = vec![(root, false)];
+        // The labels from the root down to the current node
+        let mut path = Vec::new();
+        while let Some((ord, is_visited)) = stack.pop() {
+            let has_children = embedding.children(ord).next().is_some();
+            if is_visited {
+                if has_children {
+                    xml.end_elem()?;
+                }
+                xml.end_elem()?;
+                path.pop();
+                continue;
+            }
+            let data = &embedding[ord];
+            let label = data.text.replace('\n', " ");
+            let title = match &data.tooltip {
+                Some(tooltip) => format!("{}\n{}", label, tooltip),
+                None => label.clone(),
+            };
+            path.push(label);
+            self.begin_node(xml, data, has_children)?;
+            Self::write_text_elem(xml, "title", title.as_str())?;
+            Self::write_text_elem(xml, "desc", Self::path_description(&path).as_str())?;
+
+            if let Some(edge) = geometry.edge_to(ord) {
+                // Draw a line from the nodes parent down to this node
+                self.begin_line(xml, &edge)?;
+                xml.attr("class", "edge")?;
+                xml.attr("aria-hidden", "true")?;
+                xml.end_elem()?;
+            }
+            if let Some(url) = &data.url {
+                xml.begin_elem("a")?;
+                xml.attr_esc("href", url.as_str())?;
+                self.write_text(
+                    xml,
+                    data,
+                    geometry.node(ord).unwrap(),
+                    embedding.diff_status(ord),
                 )?;
-                xml.attr("x2", format!("{}", (Self::scale_x(data.x_center))).as_str())?;
-                xml.attr("y2", format!("{}", (y - FONT_Y_SIZE)).as_str())?;
-                xml.attr("stroke", "black")?;
                 xml.end_elem()?;
+            } else {
+                self.write_text(
+                    xml,
+                    data,
+                    geometry.node(ord).unwrap(),
+                    embedding.diff_status(ord),
+                )?;
+            }
+
+            stack.push((ord, true));
+            if has_children {
+                xml.begin_elem("g")?;
+                xml.attr("role", "group")?;
+                let first_child = stack.len();
+                stack.extend(embedding.children(ord).map(|child| (child.ord, false)));
+                stack[first_child..].reverse();
             }
         }
+        Ok(())
+    }
 
-        xml.end_elem()?;
-        xml.close()?;
-        xml.flush()?;
+    /// The description of a node with the given path of labels from its root.
+    fn path_description(path: &[String]) -> String {
+        if path.len() <= MAX_PATH_LABELS {
+            return path.join(" / ");
+        }
+        let tail = &path[path.len() - (MAX_PATH_LABELS - 1)..];
+        format!("{} / … / {}", path[0], tail.join(" / "))
+    }
 
+    /// Begins the group of one node, the caller needs to end it.
+    fn begin_node<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        data: &PlacedTreeItem,
+        has_children: bool,
+    ) -> Result {
+        let mut classes = format!("node depth-{}", data.y_order);
+        if !has_children {
+            classes.push_str(" leaf");
+        }
+        if data.is_emphasized {
+            classes.push_str(" emphasized");
+        }
+        xml.begin_elem("g")?;
+        match &self.node_id {
+            Some(node_id) => xml.attr_esc("id", node_id(data).as_str())?,
+            None => xml.attr("id", format!("node-{}", data.ord).as_str())?,
+        }
+        xml.attr("class", classes.as_str())?;
+        xml.attr("role", "treeitem")?;
+        xml.attr("aria-level", format!("{}", data.y_order + 1).as_str())
+    }
+
+    /// The class of texts with the given diff status.
+    fn diff_class(status: DiffStatus) -> &'static str {
+        match status {
+            DiffStatus::Unchanged => "unchanged",
+            DiffStatus::Inserted => "inserted",
+            DiffStatus::Deleted => "deleted",
+            DiffStatus::Moved => "moved",
+            DiffStatus::Relabelled => "relabelled",
+        }
+    }
+
+    /// The color of texts with the given diff status.
+    fn diff_color(&self, status: DiffStatus) -> &str {
+        match status {
+            DiffStatus::Unchanged => &self.theme.text,
+            DiffStatus::Inserted => &self.theme.inserted,
+            DiffStatus::Deleted => &self.theme.deleted,
+            DiffStatus::Moved => &self.theme.moved,
+            DiffStatus::Relabelled => &self.theme.relabelled,
+        }
+    }
+}
+
+///
+/// The concrete implementation of the `Drawer` trait for `SvgDrawer`.
+///
+impl Drawer for SvgDrawer {
+    ///
+    /// The concrete implementation of the `Drawer::draw` trait method.
+    /// The realization is as it is - with no way to configure for instance the font used.
+    /// This decision was mode for the sake of simplicity.
+    ///
+    /// Anyway it should be easy to provide ones own Drawer implementation that fits the concrete
+    /// use case better.
+    /// When using the Layouter API you can set the Drawer instance by calling the `with_drawer`
+    /// method.
+    ///
+    /// # Panics
+    ///
+    /// The method should not panic. If you encounter a panic this should be originated from
+    /// bugs in coding. Please report such panics.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    fn draw(&self, file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        let mut xml = Self::create_document(file_name)?;
+        self.write_svg(&mut xml, embedding)?;
+        Self::end_document(xml)?;
         Ok(())
     }
 }
@@ -0,0 +1,346 @@
+//! The module with the space-filling diagrams of the `SvgDrawer`: icicle, sunburst and treemap.
+
+use crate::partition::Partition;
+use crate::svg_drawer::{Result, SvgDrawer, FONT_Y_SIZE};
+use crate::treemap::Treemap;
+use std::f64::consts::PI;
+use std::io::Write;
+use std::path::Path;
+use xml_writer::XmlWriter;
+
+use super::geometry::{Point, Rect, Size};
+
+const ROW_HEIGHT: f64 = 25.0;
+const MARGIN: f64 = 10.0;
+
+impl SvgDrawer {
+    ///
+    /// Draws the partition as an icicle diagram of the given width, i.e. each node is a rectangle
+    /// on the row of its depth whose width is proportional to the node's weight.
+    /// Each subtree of the root is filled with its own color, the labels are clipped to the
+    /// nodes' rectangles. Nodes with zero weight are omitted.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn draw_icicle(&self, file_name: &Path, partition: &Partition, width: f64) -> Result {
+        let mut xml = Self::create_document(file_name)?;
+
+        self.begin_document(
+            &mut xml,
+            Size {
+                width: width + 2.0 * MARGIN,
+                height: partition.depth() as f64 * ROW_HEIGHT + 2.0 * MARGIN,
+            },
+            "img",
+        )?;
+
+        let colors = self.fill_colors(
+            &partition
+                .iter()
+                .map(|item| item.parent)
+                .collect::<Vec<Option<usize>>>(),
+        );
+        // Parents first, thus from the root downwards
+        for item in partition.iter().rev() {
+            if item.extent <= 0.0 {
+                continue;
+            }
+            let rect = Rect {
+                x: MARGIN + item.start * width,
+                y: MARGIN + item.depth as f64 * ROW_HEIGHT,
+                width: item.extent * width,
+                height: ROW_HEIGHT,
+            };
+
+            xml.begin_elem("clipPath")?;
+            xml.attr("id", Self::clip_id(item.ord).as_str())?;
+            Self::write_rect(&mut xml, &rect)?;
+            xml.end_elem()?;
+            xml.end_elem()?;
+
+            Self::write_rect(&mut xml, &rect)?;
+            xml.attr_esc("fill", colors[item.ord])?;
+            xml.attr_esc("stroke", self.theme().background.as_str())?;
+            xml.end_elem()?;
+
+            Self::write_clipped_label(
+                &mut xml,
+                &item.text,
+                item.is_emphasized,
+                &Self::clip_id(item.ord),
+                Point {
+                    x: rect.x + FONT_Y_SIZE / 2.0,
+                    y: rect.y + (ROW_HEIGHT + FONT_Y_SIZE) / 2.0,
+                },
+                "start",
+            )?;
+        }
+
+        Self::end_document(xml)
+    }
+
+    ///
+    /// Draws the partition as a sunburst diagram of the given radius, i.e. the root is a disk in
+    /// the center and each other node is an annular sector on the ring of its depth whose angle
+    /// is proportional to the node's weight. The nodes start at the top and run clockwise.
+    /// Each subtree of the root is filled with its own color, the labels are centered within
+    /// the nodes' sectors and clipped to them. Nodes with zero weight are omitted.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn draw_sunburst(&self, file_name: &Path, partition: &Partition, radius: f64) -> Result {
+        let mut xml = Self::create_document(file_name)?;
+
+        let size = 2.0 * (radius + MARGIN);
+        self.begin_document(
+            &mut xml,
+            Size {
+                width: size,
+                height: size,
+            },
+            "img",
+        )?;
+
+        let center = Point {
+            x: radius + MARGIN,
+            y: radius + MARGIN,
+        };
+        let ring_width = radius / std::cmp::max(partition.depth(), 1) as f64;
+        let colors = self.fill_colors(
+            &partition
+                .iter()
+                .map(|item| item.parent)
+                .collect::<Vec<Option<usize>>>(),
+        );
+        // Parents first, thus from the center outwards
+        for item in partition.iter().rev() {
+            if item.extent <= 0.0 {
+                continue;
+            }
+            let inner = item.depth as f64 * ring_width;
+            let outer = inner + ring_width;
+            let path = Self::sector_path(center, inner, outer, item.start, item.extent);
+
+            xml.begin_elem("clipPath")?;
+            xml.attr("id", Self::clip_id(item.ord).as_str())?;
+            xml.begin_elem("path")?;
+            xml.attr("d", path.as_str())?;
+            xml.end_elem()?;
+            xml.end_elem()?;
+
+            xml.begin_elem("path")?;
+            xml.attr("d", path.as_str())?;
+            xml.attr_esc("fill", colors[item.ord])?;
+            xml.attr("fill-rule", "evenodd")?;
+            xml.attr_esc("stroke", self.theme().background.as_str())?;
+            xml.end_elem()?;
+
+            let label_position = if item.depth == 0 {
+                center
+            } else {
+                Self::polar(
+                    center,
+                    (inner + outer) / 2.0,
+                    (item.start + item.extent / 2.0) * 2.0 * PI,
+                )
+            };
+            Self::write_clipped_label(
+                &mut xml,
+                &item.text,
+                item.is_emphasized,
+                &Self::clip_id(item.ord),
+                Point {
+                    x: label_position.x,
+                    y: label_position.y + FONT_Y_SIZE / 2.0,
+                },
+                "middle",
+            )?;
+        }
+
+        Self::end_document(xml)
+    }
+
+    ///
+    /// Draws the treemap. Each node is a rectangle, internal nodes show their label in their
+    /// header strip, leaves at the top of their rectangle. Each subtree of the root is filled with
+    /// its own color, the labels are clipped to the headers and the leaves' rectangles
+    /// respectively. Nodes with an empty rectangle are omitted.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn draw_treemap(&self, file_name: &Path, treemap: &Treemap) -> Result {
+        let mut xml = Self::create_document(file_name)?;
+
+        self.begin_document(
+            &mut xml,
+            Size {
+                width: treemap.size().width + 2.0 * MARGIN,
+                height: treemap.size().height + 2.0 * MARGIN,
+            },
+            "img",
+        )?;
+
+        let colors = self.fill_colors(
+            &treemap
+                .iter()
+                .map(|item| item.parent)
+                .collect::<Vec<Option<usize>>>(),
+        );
+        let translate = |rect: &Rect| Rect {
+            x: rect.x + MARGIN,
+            y: rect.y + MARGIN,
+            ..*rect
+        };
+        // Parents first, thus children are painted over their parents
+        for item in treemap.iter().rev() {
+            if item.rect.width <= 0.0 || item.rect.height <= 0.0 {
+                continue;
+            }
+            let rect = translate(&item.rect);
+            let label_rect = if item.header.height > 0.0 {
+                translate(&item.header)
+            } else {
+                rect
+            };
+
+            xml.begin_elem("clipPath")?;
+            xml.attr("id", Self::clip_id(item.ord).as_str())?;
+            Self::write_rect(&mut xml, &label_rect)?;
+            xml.end_elem()?;
+            xml.end_elem()?;
+
+            Self::write_rect(&mut xml, &rect)?;
+            xml.attr_esc("fill", colors[item.ord])?;
+            xml.attr_esc("stroke", self.theme().border.as_str())?;
+            xml.end_elem()?;
+
+            Self::write_clipped_label(
+                &mut xml,
+                &item.text,
+                item.is_emphasized,
+                &Self::clip_id(item.ord),
+                Point {
+                    x: label_rect.x + FONT_Y_SIZE / 2.0,
+                    y: label_rect.y + FONT_Y_SIZE * 1.25,
+                },
+                "start",
+            )?;
+        }
+
+        Self::end_document(xml)
+    }
+
+    /// Returns the fill color of each item given by its parent's `ord`, indexed by `ord`.
+    /// The `ord`s need to reflect a post-ordering of the nodes.
+    fn fill_colors(&self, parents: &[Option<usize>]) -> Vec<&str> {
+        let theme = self.theme();
+        let mut colors = vec![theme.root_fill.as_str(); parents.len()];
+        let root = match parents.len().checked_sub(1) {
+            Some(root) => root,
+            None => return colors,
+        };
+        // The root's children are ordered from left to right
+        for (i, ord) in (0..root)
+            .filter(|ord| parents[*ord] == Some(root))
+            .enumerate()
+        {
+            if !theme.palette.is_empty() {
+                colors[ord] = theme.palette[i % theme.palette.len()].as_str();
+            }
+        }
+        // Parents always have a greater `ord` than their children, so we visit them first
+        for ord in (0..root).rev() {
+            match parents[ord] {
+                Some(parent) if parent != root => colors[ord] = colors[parent],
+                _ => (),
+            }
+        }
+        colors
+    }
+
+    /// The id of the clip path of the item with the given `ord`.
+    fn clip_id(ord: usize) -> String {
+        format!("clip{}", ord)
+    }
+
+    /// Begins a rect element, the caller needs to end it.
+    fn write_rect<W: Write>(xml: &mut XmlWriter<W>, rect: &Rect) -> Result {
+        xml.begin_elem("rect")?;
+        xml.attr("x", format!("{}", rect.x).as_str())?;
+        xml.attr("y", format!("{}", rect.y).as_str())?;
+        xml.attr("width", format!("{}", rect.width).as_str())?;
+        xml.attr("height", format!("{}", rect.height).as_str())
+    }
+
+    /// Writes a label clipped to the clip path with the given id.
+    fn write_clipped_label<W: Write>(
+        xml: &mut XmlWriter<W>,
+        text: &str,
+        is_emphasized: bool,
+        clip_id: &str,
+        position: Point,
+        anchor: &str,
+    ) -> Result {
+        xml.begin_elem("text")?;
+        xml.attr("x", format!("{}", position.x).as_str())?;
+        xml.attr("y", format!("{}", position.y).as_str())?;
+        xml.attr("text-anchor", anchor)?;
+        xml.attr("clip-path", format!("url(#{})", clip_id).as_str())?;
+        Self::write_font(xml, is_emphasized, false)?;
+        xml.text(text)?;
+        xml.end_elem()
+    }
+
+    /// The point at the given radius and angle, the angle is measured clockwise from the top.
+    fn polar(center: Point, radius: f64, angle: f64) -> Point {
+        Point {
+            x: center.x + radius * angle.sin(),
+            y: center.y - radius * angle.cos(),
+        }
+    }
+
+    /// The path of an annular sector given by its start and extent within the unit interval.
+    fn sector_path(center: Point, inner: f64, outer: f64, start: f64, extent: f64) -> String {
+        let point = |radius: f64, angle: f64| {
+            let p = Self::polar(center, radius, angle);
+            format!("{} {}", p.x, p.y)
+        };
+        if extent >= 1.0 {
+            // A full ring is drawn as two half rings, its hole is cut out by the even-odd rule
+            let ring = |radius: f64| {
+                format!(
+                    "M {} A {r} {r} 0 1 1 {} A {r} {r} 0 1 1 {} Z",
+                    point(radius, 0.0),
+                    point(radius, PI),
+                    point(radius, 0.0),
+                    r = radius
+                )
+            };
+            if inner > 0.0 {
+                format!("{} {}", ring(outer), ring(inner))
+            } else {
+                ring(outer)
+            }
+        } else {
+            let start_angle = start * 2.0 * PI;
+            let end_angle = (start + extent) * 2.0 * PI;
+            let large_arc = if extent > 0.5 { 1 } else { 0 };
+            format!(
+                "M {} A {o} {o} 0 {l} 1 {} L {} A {i} {i} 0 {l} 0 {} Z",
+                point(outer, start_angle),
+                point(outer, end_angle),
+                point(inner, end_angle),
+                point(inner, start_angle),
+                o = outer,
+                i = inner,
+                l = large_arc
+            )
+        }
+    }
+}
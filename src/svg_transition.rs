@@ -0,0 +1,236 @@
+//! The module with the animated transition between two embeddings in Svg format.
+
+use crate::embedder::{Embedding, PlacedTreeItem};
+use crate::geometry::{Edge, Size};
+use crate::svg_drawer::{Result, SvgDrawer};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::io::Write;
+use std::path::Path;
+use xml_writer::XmlWriter;
+
+///
+/// The `SvgTransition` type writes an animated Svg that shows the transition from one embedding
+/// to another one, for instance of two states of a tree that is rewritten step by step.
+///
+/// The nodes of both embeddings are matched by a key, by default their `node_id`. Matched nodes
+/// move from their old to their new position, nodes that only exist in the new embedding fade
+/// in and nodes that only exist in the old embedding fade out. The same applies to the edges.
+/// The animation uses SMIL animation elements, the single states look exactly as the
+/// `SvgDrawer` paints them.
+///
+#[derive(Debug, Clone)]
+pub struct SvgTransition {
+    /// The duration of the animation in seconds
+    duration: f64,
+    /// The drawer that paints the single states
+    drawer: SvgDrawer,
+}
+
+impl Default for SvgTransition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgTransition {
+    /// Creates a new `SvgTransition` with a duration of one second.
+    pub fn new() -> Self {
+        Self {
+            duration: 1.0,
+            drawer: SvgDrawer::new(),
+        }
+    }
+
+    /// Sets the duration of the animation in seconds.
+    pub fn with_duration(self, duration: f64) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Sets the drawer whose options and themes are used to paint the single states.
+    pub fn with_drawer(self, drawer: SvgDrawer) -> Self {
+        Self { drawer, ..self }
+    }
+
+    ///
+    /// Writes the animated transition from the embedding `from` to the embedding `to`. The nodes
+    /// are matched by their `node_id`.
+    /// Note that `id_tree` reuses the ids of removed nodes for nodes inserted later. Use
+    /// `draw_with_key` if the tree changed this way.
+    ///
+    pub fn draw(&self, file_name: &Path, from: &Embedding, to: &Embedding) -> Result {
+        self.draw_with_key(file_name, from, to, |item| item.node_id.clone())
+    }
+
+    ///
+    /// Writes the animated transition from the embedding `from` to the embedding `to`. The nodes
+    /// are matched by the key the function `key` returns. Items without a key are never matched.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n).
+    ///
+    pub fn draw_with_key<K, F>(
+        &self,
+        file_name: &Path,
+        from: &Embedding,
+        to: &Embedding,
+        key: F,
+    ) -> Result
+    where
+        K: Eq + Hash,
+        F: Fn(&PlacedTreeItem) -> Option<K>,
+    {
+        let drawer = &self.drawer;
+        let from_geometry = drawer.geometry(from);
+        let to_geometry = drawer.geometry(to);
+
+        let from_keys = Self::keys(from, &key);
+        let to_keys = Self::keys(to, &key);
+        // The keys of the parent and the child of each edge
+        let edge_keys = |embedding: &Embedding| {
+            embedding
+                .iter()
+                .filter_map(|item| Some((key(embedding.parent(item.ord)?)?, key(item)?)))
+                .collect::<HashSet<(K, K)>>()
+        };
+        let from_edges = edge_keys(from);
+        let to_edges = edge_keys(to);
+
+        let mut xml = SvgDrawer::create_document(file_name)?;
+        let size = Size {
+            width: from_geometry.size().width.max(to_geometry.size().width),
+            height: from_geometry.size().height.max(to_geometry.size().height),
+        };
+        drawer.begin_document(&mut xml, size, "img")?;
+        drawer.write_captions(&mut xml, to, &to_geometry)?;
+
+        // Fade out the nodes and edges that don't exist anymore
+        for data in from {
+            let data_key = key(data);
+            if data_key.as_ref().is_some_and(|k| to_keys.contains_key(k)) {
+                continue;
+            }
+            xml.begin_elem("g")?;
+            self.write_fade(&mut xml, false)?;
+            drawer.write_text(
+                &mut xml,
+                data,
+                from_geometry.node(data.ord).unwrap(),
+                from.diff_status(data.ord),
+            )?;
+            xml.end_elem()?;
+        }
+        for edge in from_geometry.edges() {
+            let edge_key = key(&from[edge.parent]).zip(key(&from[edge.child]));
+            if edge_key.is_some_and(|k| to_edges.contains(&k)) {
+                continue;
+            }
+            drawer.begin_line(&mut xml, &edge)?;
+            self.write_fade(&mut xml, false)?;
+            xml.end_elem()?;
+        }
+
+        // Move the remaining nodes and edges and fade in the new ones
+        for data in to {
+            let node = to_geometry.node(data.ord).unwrap();
+            let old_node = key(data)
+                .and_then(|k| from_keys.get(&k))
+                .map(|ord| from_geometry.node(*ord).unwrap());
+            xml.begin_elem("g")?;
+            match old_node {
+                Some(old_node) => {
+                    xml.begin_elem("animateTransform")?;
+                    xml.attr("attributeName", "transform")?;
+                    xml.attr("type", "translate")?;
+                    xml.attr(
+                        "from",
+                        format!(
+                            "{} {}",
+                            old_node.rect.x - node.rect.x,
+                            old_node.rect.y - node.rect.y
+                        )
+                        .as_str(),
+                    )?;
+                    xml.attr("to", "0 0")?;
+                    self.write_timing(&mut xml)?;
+                    xml.end_elem()?;
+                }
+                None => self.write_fade(&mut xml, true)?,
+            }
+            drawer.write_text(&mut xml, data, node, to.diff_status(data.ord))?;
+            xml.end_elem()?;
+
+            if let Some(edge) = to_geometry.edge_to(data.ord) {
+                drawer.begin_line(&mut xml, &edge)?;
+                let old_edge = key(&to[edge.parent])
+                    .zip(key(data))
+                    .filter(|k| from_edges.contains(k))
+                    .map(|(parent_key, _)| Edge {
+                        from: from_geometry
+                            .node(from_keys[&parent_key])
+                            .unwrap()
+                            .bottom_anchor,
+                        to: old_node.unwrap().top_anchor,
+                        ..edge
+                    });
+                if let Some(old_edge) = old_edge {
+                    self.write_move(&mut xml, "x1", old_edge.from.x, edge.from.x)?;
+                    self.write_move(&mut xml, "y1", old_edge.from.y, edge.from.y)?;
+                    self.write_move(&mut xml, "x2", old_edge.to.x, edge.to.x)?;
+                    self.write_move(&mut xml, "y2", old_edge.to.y, edge.to.y)?;
+                } else {
+                    self.write_fade(&mut xml, true)?;
+                }
+                xml.end_elem()?;
+            }
+        }
+
+        SvgDrawer::end_document(xml)
+    }
+
+    /// Maps the keys of all items to their `ord`s.
+    fn keys<K, F>(embedding: &Embedding, key: &F) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: Fn(&PlacedTreeItem) -> Option<K>,
+    {
+        embedding
+            .iter()
+            .filter_map(|item| key(item).map(|k| (k, item.ord)))
+            .collect()
+    }
+
+    /// Writes the timing attributes of an animation element.
+    fn write_timing<W: Write>(&self, xml: &mut XmlWriter<W>) -> Result {
+        xml.attr("dur", format!("{}s", self.duration).as_str())?;
+        xml.attr("fill", "freeze")
+    }
+
+    /// Writes an animation that fades the parent element in or out.
+    fn write_fade<W: Write>(&self, xml: &mut XmlWriter<W>, fade_in: bool) -> Result {
+        let (from, to) = if fade_in { ("0", "1") } else { ("1", "0") };
+        xml.begin_elem("animate")?;
+        xml.attr("attributeName", "opacity")?;
+        xml.attr("from", from)?;
+        xml.attr("to", to)?;
+        self.write_timing(xml)?;
+        xml.end_elem()
+    }
+
+    /// Writes an animation that changes the given attribute of the parent element.
+    fn write_move<W: Write>(
+        &self,
+        xml: &mut XmlWriter<W>,
+        attribute: &str,
+        from: f64,
+        to: f64,
+    ) -> Result {
+        xml.begin_elem("animate")?;
+        xml.attr("attributeName", attribute)?;
+        xml.attr("from", format!("{}", from).as_str())?;
+        xml.attr("to", format!("{}", to).as_str())?;
+        self.write_timing(xml)?;
+        xml.end_elem()
+    }
+}
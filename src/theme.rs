@@ -0,0 +1,157 @@
+//! The module with the color themes of the `SvgDrawer`.
+
+#[cfg(feature = "serde_support")]
+use serde_derive::{Deserialize, Serialize};
+
+///
+/// The `Theme` type holds the colors the `SvgDrawer` paints with. The colors are given in any
+/// notation Svg accepts, e.g. `"white"` or `"#1e1e1e"`.
+///
+/// Besides the named themes custom themes can be defined. With the feature `serde_support` they
+/// can be read from configuration files, e.g. in JSON or TOML, missing colors are taken from the
+/// light theme.
+///
+/// ```
+/// use id_tree_layout::theme::Theme;
+///
+/// let theme = Theme {
+///     background: "ivory".to_string(),
+///     ..Theme::light()
+/// };
+/// assert_eq!(Some(Theme::dark()), Theme::named("dark"));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(default))]
+pub struct Theme {
+    /// The color of the background
+    pub background: String,
+    /// The color of the nodes' texts and the captions
+    pub text: String,
+    /// The color of the edges
+    pub line: String,
+    /// The color of the frames of the treemap's rectangles
+    pub border: String,
+    /// The fill color of the root of the space-filling diagrams
+    pub root_fill: String,
+    /// The fill colors of the root's subtrees in the space-filling diagrams, used cyclically
+    pub palette: Vec<String>,
+    /// The color of the texts of inserted nodes in a diff
+    pub inserted: String,
+    /// The color of the texts of deleted nodes in a diff
+    pub deleted: String,
+    /// The color of the texts of moved nodes in a diff
+    pub moved: String,
+    /// The color of the texts of relabelled nodes in a diff
+    pub relabelled: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme {
+    ///
+    /// The default theme, dark colors on a white background.
+    ///
+    pub fn light() -> Self {
+        Self {
+            background: "white".to_string(),
+            text: "black".to_string(),
+            line: "black".to_string(),
+            border: "#555555".to_string(),
+            root_fill: "#dddddd".to_string(),
+            palette: colors(&[
+                "#8dd3c7", "#ffffb3", "#bebada", "#fb8072", "#80b1d3", "#fdb462", "#b3de69",
+                "#fccde5",
+            ]),
+            inserted: "green".to_string(),
+            deleted: "red".to_string(),
+            moved: "blue".to_string(),
+            relabelled: "darkorange".to_string(),
+        }
+    }
+
+    ///
+    /// Light colors on a dark background, e.g. for documentation sites in dark mode.
+    ///
+    pub fn dark() -> Self {
+        Self {
+            background: "#1e1e1e".to_string(),
+            text: "#d4d4d4".to_string(),
+            line: "#a0a0a0".to_string(),
+            border: "#808080".to_string(),
+            root_fill: "#3c3c3c".to_string(),
+            palette: colors(&[
+                "#1b6f63", "#7a5c00", "#4b4785", "#8c3b2e", "#2d5f85", "#85501a", "#4c6b1f",
+                "#7a3d5c",
+            ]),
+            inserted: "#89d185".to_string(),
+            deleted: "#f48771".to_string(),
+            moved: "#75beff".to_string(),
+            relabelled: "#e2c08d".to_string(),
+        }
+    }
+
+    ///
+    /// White and saturated colors on a black background for a maximal contrast.
+    ///
+    pub fn high_contrast() -> Self {
+        Self {
+            background: "black".to_string(),
+            text: "white".to_string(),
+            line: "yellow".to_string(),
+            border: "white".to_string(),
+            root_fill: "black".to_string(),
+            palette: colors(&[
+                "#000080", "#800000", "#006400", "#4b0082", "#8b4513", "#008080",
+            ]),
+            inserted: "lime".to_string(),
+            deleted: "#ff6060".to_string(),
+            moved: "cyan".to_string(),
+            relabelled: "orange".to_string(),
+        }
+    }
+
+    ///
+    /// Shades of gray on a white background, suitable for printing. Deleted and moved nodes
+    /// remain distinguishable by their text decorations.
+    ///
+    pub fn grayscale() -> Self {
+        Self {
+            background: "white".to_string(),
+            text: "black".to_string(),
+            line: "black".to_string(),
+            border: "black".to_string(),
+            root_fill: "#eeeeee".to_string(),
+            palette: colors(&[
+                "#d9d9d9", "#bdbdbd", "#f0f0f0", "#969696", "#e0e0e0", "#a8a8a8",
+            ]),
+            inserted: "black".to_string(),
+            deleted: "#777777".to_string(),
+            moved: "#333333".to_string(),
+            relabelled: "#555555".to_string(),
+        }
+    }
+
+    ///
+    /// Returns the theme with the given name, which is one of `light`, `dark`, `high-contrast`
+    /// and `grayscale`, or `print` as an alias of the latter.
+    ///
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(Self::light()),
+            "dark" => Some(Self::dark()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "grayscale" | "print" => Some(Self::grayscale()),
+            _ => None,
+        }
+    }
+}
+
+fn colors(colors: &[&str]) -> Vec<String> {
+    colors.iter().map(|color| color.to_string()).collect()
+}
@@ -0,0 +1,358 @@
+//! The module with the squarified treemap layout of a tree.
+
+use crate::geometry::{Rect, Size};
+use crate::visualize::Visualize;
+use crate::weights::{accumulate_weights, NodeValue};
+use id_tree::{NodeId, Tree};
+
+///
+/// The PlacedTreemapItem is the information of one single tree node within a `Treemap`.
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlacedTreemapItem {
+    /// The nodes depth, root has depth 0
+    pub depth: usize,
+    /// The rectangle the node occupies, it contains the rectangles of all its children
+    pub rect: Rect,
+    /// The header strip at the top of an internal node's rectangle, its height is zero for leaves
+    pub header: Rect,
+    /// The total weight of the node, i.e. its own weight plus the total weights of its children
+    pub weight: f64,
+    /// The text representation of the nodes data - created by the `Visualize` trait's implementation
+    pub text: String,
+    /// The *emphasize* property obtained from the `Visualize` trait
+    pub is_emphasized: bool,
+    /// The parent's `ord`, if there is one
+    pub parent: Option<usize>,
+    /// A unique number reflecting the topological post-ordering of the nodes in the tree
+    pub ord: usize,
+    /// The id of the tree node
+    pub node_id: Option<NodeId>,
+}
+
+///
+/// The Treemap is the result of the `Treemapper`. Its items are ordered by their `ord`.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Treemap {
+    /// The items ordered by `ord`
+    items: Vec<PlacedTreemapItem>,
+    /// The size of the whole treemap
+    size: Size,
+}
+
+impl Treemap {
+    ///
+    /// Returns the size of the whole treemap, i.e. the size of the root's rectangle.
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl std::ops::Deref for Treemap {
+    type Target = [PlacedTreemapItem];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<'a> IntoIterator for &'a Treemap {
+    type Item = &'a PlacedTreemapItem;
+    type IntoIter = std::slice::Iter<'a, PlacedTreemapItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+///
+/// The Treemapper type arranges the nodes of a tree as nested rectangles using the squarified
+/// treemap algorithm of Bruls, Huizing and van Wijk, which keeps the aspect ratios of the
+/// rectangles close to one.
+///
+/// Each internal node gets a header strip at its top for its label, its children are placed
+/// within the remaining area, inset by the padding. The area available for the children is
+/// divided proportionally to their weights. By default the weights are obtained from the
+/// `Visualize` trait's `weight` method, nodes without a weight count 1 if they are leaves and 0
+/// otherwise. Use `with_weights` to supply the weights by a function instead.
+///
+/// ```
+/// use id_tree_layout::{geometry::Size, Treemapper, Visualize};
+/// use id_tree::{InsertBehavior::*, Node, Tree, TreeBuilder};
+///
+/// struct MyNodeData(i32);
+///
+/// impl Visualize for MyNodeData {
+///     fn visualize(&self) -> std::string::String { self.0.to_string() }
+/// }
+///
+/// let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+/// let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+/// tree.insert(Node::new(MyNodeData(1)), UnderNode(&root)).unwrap();
+/// tree.insert(Node::new(MyNodeData(3)), UnderNode(&root)).unwrap();
+///
+/// let treemap = Treemapper::new()
+///     .with_weights(|d: &MyNodeData| d.0 as f64)
+///     .with_padding(0.0)
+///     .with_header_height(0.0)
+///     .layout(&tree, Size { width: 400.0, height: 100.0 });
+/// assert_eq!(300.0, treemap[1].rect.width);
+/// assert_eq!(100.0, treemap[0].rect.width);
+/// ```
+///
+pub struct Treemapper<T>
+where
+    T: Visualize,
+{
+    weight: Option<NodeValue<T>>,
+    padding: f64,
+    header_height: f64,
+}
+
+impl<T> Default for Treemapper<T>
+where
+    T: Visualize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Treemapper<T>
+where
+    T: Visualize,
+{
+    ///
+    /// Creates a new Treemapper that obtains the weights from the `Visualize` trait, with a
+    /// padding of 4 and a header height of 15.
+    ///
+    pub fn new() -> Self {
+        Self {
+            weight: None,
+            padding: 4.0,
+            header_height: 15.0,
+        }
+    }
+
+    ///
+    /// Sets the function that returns the own weight of a node's data. The total weight of a
+    /// node is its own weight plus the total weights of its children, so the own weight of an
+    /// internal node remains as an empty area within its rectangle.
+    /// Negative weights are treated as zero.
+    ///
+    pub fn with_weights<F>(self, weight: F) -> Self
+    where
+        F: Fn(&T) -> f64 + 'static,
+    {
+        Self {
+            weight: Some(Box::new(weight)),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the distance between the border of an internal node and the rectangles of its
+    /// children.
+    ///
+    pub fn with_padding(self, padding: f64) -> Self {
+        Self { padding, ..self }
+    }
+
+    ///
+    /// Sets the height of the header strip of internal nodes.
+    ///
+    pub fn with_header_height(self, header_height: f64) -> Self {
+        Self {
+            header_height,
+            ..self
+        }
+    }
+
+    ///
+    /// This method creates the treemap of the given tree that fills a rectangle of the given
+    /// size. Children of nodes that are too small for their padding and header get empty
+    /// rectangles.
+    ///
+    /// # Complexity
+    ///
+    /// The algorithm is of time complexity class O(n log n).
+    ///
+    pub fn layout(&self, tree: &Tree<T>, size: Size) -> Treemap {
+        let mut items: Vec<PlacedTreemapItem> = Vec::new();
+        let mut children: Vec<Vec<usize>> = Vec::new();
+        let mut own_weights: Vec<f64> = Vec::new();
+        for (ord, node) in accumulate_weights(tree, self.weight.as_ref())
+            .into_iter()
+            .enumerate()
+        {
+            let data = tree.get(&node.node_id).unwrap().data();
+            items.push(PlacedTreemapItem {
+                weight: node.weight,
+                text: data.visualize(),
+                is_emphasized: data.emphasize(),
+                parent: node.parent,
+                ord,
+                node_id: Some(node.node_id),
+                ..Default::default()
+            });
+            children.push(node.children);
+            own_weights.push(node.own_weight);
+        }
+
+        if let Some(root) = items.last_mut() {
+            root.rect = Rect {
+                x: 0.0,
+                y: 0.0,
+                width: size.width,
+                height: size.height,
+            };
+        }
+        // Parents always have a greater `ord` than their children, so we visit them first
+        for ord in (0..items.len()).rev() {
+            if children[ord].is_empty() {
+                continue;
+            }
+            let rect = items[ord].rect;
+            let header = Rect {
+                height: self.header_height.min(rect.height),
+                ..rect
+            };
+            items[ord].header = header;
+
+            let content = Rect {
+                x: rect.x + self.padding,
+                y: header.bottom(),
+                width: (rect.width - 2.0 * self.padding).max(0.0),
+                height: (rect.height - header.height - self.padding).max(0.0),
+            };
+            // The own weight is laid out as an item without an `ord` that stays empty
+            let mut weights = children[ord]
+                .iter()
+                .map(|child| (Some(*child), items[*child].weight))
+                .chain(std::iter::once((None, own_weights[ord])))
+                .filter(|(_, weight)| *weight > 0.0)
+                .collect::<Vec<(Option<usize>, f64)>>();
+            let depth = items[ord].depth + 1;
+            for child in &children[ord] {
+                let item = &mut items[*child];
+                item.depth = depth;
+                item.rect = Rect {
+                    width: 0.0,
+                    height: 0.0,
+                    ..content
+                };
+            }
+            for (child, child_rect) in Self::squarify(&mut weights, content, items[ord].weight) {
+                if let Some(child) = child {
+                    items[child].rect = child_rect;
+                }
+            }
+        }
+
+        Treemap { items, size }
+    }
+
+    ///
+    /// Divides the rectangle into rectangles whose areas are proportional to the given weights.
+    /// The weights are sorted descending, then rows of rectangles are placed along the shorter
+    /// side of the remaining area as long as the worst aspect ratio within the row improves.
+    ///
+    fn squarify(
+        weights: &mut [(Option<usize>, f64)],
+        rect: Rect,
+        total_weight: f64,
+    ) -> Vec<(Option<usize>, Rect)> {
+        let mut result = Vec::new();
+        if total_weight <= 0.0 || rect.width <= 0.0 || rect.height <= 0.0 {
+            return result;
+        }
+        weights.sort_by(|(_, w1), (_, w2)| w2.total_cmp(w1));
+        let scale = rect.width * rect.height / total_weight;
+
+        // The worst aspect ratio of a row with the given sum, maximum and minimum area
+        let worst = |sum: f64, max: f64, min: f64, side: f64| {
+            let side = side * side;
+            let sum = sum * sum;
+            f64::max(side * max / sum, sum / (side * min))
+        };
+
+        let mut rest = rect;
+        let mut start = 0;
+        while start < weights.len() {
+            let side = rest.width.min(rest.height);
+            let first = weights[start].1 * scale;
+            let (mut sum, mut min) = (first, first);
+            let mut end = start + 1;
+            while end < weights.len() {
+                let area = weights[end].1 * scale;
+                // The areas are sorted descending, thus the first one is the maximum
+                if worst(sum + area, first, area, side) > worst(sum, first, min, side) {
+                    break;
+                }
+                sum += area;
+                min = area;
+                end += 1;
+            }
+
+            if rest.width >= rest.height {
+                // The row is a column at the left side of the remaining area
+                let width = if rest.height > 0.0 {
+                    sum / rest.height
+                } else {
+                    0.0
+                };
+                let mut y = rest.y;
+                for (child, weight) in &weights[start..end] {
+                    let height = if width > 0.0 {
+                        weight * scale / width
+                    } else {
+                        0.0
+                    };
+                    result.push((
+                        *child,
+                        Rect {
+                            x: rest.x,
+                            y,
+                            width,
+                            height,
+                        },
+                    ));
+                    y += height;
+                }
+                rest.x += width;
+                rest.width = (rest.width - width).max(0.0);
+            } else {
+                // The row is placed at the top of the remaining area
+                let height = if rest.width > 0.0 {
+                    sum / rest.width
+                } else {
+                    0.0
+                };
+                let mut x = rest.x;
+                for (child, weight) in &weights[start..end] {
+                    let width = if height > 0.0 {
+                        weight * scale / height
+                    } else {
+                        0.0
+                    };
+                    result.push((
+                        *child,
+                        Rect {
+                            x,
+                            y: rest.y,
+                            width,
+                            height,
+                        },
+                    ));
+                    x += width;
+                }
+                rest.y += height;
+                rest.height = (rest.height - height).max(0.0);
+            }
+            start = end;
+        }
+        result
+    }
+}
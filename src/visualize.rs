@@ -12,4 +12,39 @@ pub trait Visualize {
     fn emphasize(&self) -> bool {
         false
     }
+
+    /// Returns the node's own weight, e.g. the size of a file or the run time of a function.
+    /// Layouts that size the nodes proportionally, like the `Partitioner` and the `Treemapper`,
+    /// use it unless they are given a weight function explicitly. Without a weight a leaf counts
+    /// as 1 and an internal node as 0.
+    fn weight(&self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the width of the node's content in logical coordinate units, i.e. in characters.
+    /// If there is a hint the `Embedder` uses it instead of the length of the string
+    /// representation, for instance to reserve the space for an icon of fixed size.
+    fn width_hint(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the height of the node's content in lines of text.
+    /// If there is a hint drawers use it instead of the number of lines of the string
+    /// representation.
+    fn height_hint(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns additional information about the node, e.g. the span of the source code a node
+    /// of a parse tree was created from. The `SvgDrawer` shows it when the pointer hovers over
+    /// the node.
+    fn tooltip(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the URL of a document about the node, e.g. the documentation of a grammar rule.
+    /// The `SvgDrawer` turns the node's text into a hyperlink to it.
+    fn url(&self) -> Option<String> {
+        None
+    }
 }
@@ -0,0 +1,85 @@
+//! The module with the weights of the nodes shared by the space-filling layouts.
+
+use crate::visualize::Visualize;
+use id_tree::{NodeId, Tree};
+use std::collections::HashMap;
+
+///
+/// Internal type of numeric values obtained from the tree's node data
+///
+pub(crate) type NodeValue<T> = Box<dyn Fn(&T) -> f64>;
+
+///
+/// Internal type of one node with its accumulated weight
+///
+pub(crate) struct WeightedNode {
+    /// The id of the node within the tree
+    pub node_id: NodeId,
+    /// The parent's `ord`, if any
+    pub parent: Option<usize>,
+    /// The children's `ord`s from left to right
+    pub children: Vec<usize>,
+    /// The node's own weight, never negative
+    pub own_weight: f64,
+    /// The node's own weight plus the weights of its children
+    pub weight: f64,
+}
+
+///
+/// Accumulates the weights of the nodes bottom up and returns them in post-order, i.e. indexed
+/// by their `ord`. The own weight of a node is obtained from the given function or, if it is
+/// missing, from the `Visualize` trait's `weight` method. Nodes without a weight count 1 if they
+/// are leaves and 0 otherwise, negative weights count 0.
+///
+/// # Complexity
+///
+/// The algorithm is of time complexity class O(n).
+///
+pub(crate) fn accumulate_weights<T>(
+    tree: &Tree<T>,
+    weight: Option<&NodeValue<T>>,
+) -> Vec<WeightedNode>
+where
+    T: Visualize,
+{
+    let mut nodes: Vec<WeightedNode> = Vec::new();
+    if let Some(root_node_id) = tree.root_node_id() {
+        let mut ords: HashMap<NodeId, usize> = HashMap::new();
+        for (ord, node_id) in tree
+            .traverse_post_order_ids(root_node_id)
+            .unwrap()
+            .enumerate()
+        {
+            let node = tree.get(&node_id).unwrap();
+            let children = node
+                .children()
+                .iter()
+                .map(|child_node_id| ords[child_node_id])
+                .collect::<Vec<usize>>();
+            let own_weight = match weight {
+                Some(weight) => weight(node.data()),
+                None => node
+                    .data()
+                    .weight()
+                    .unwrap_or(if children.is_empty() { 1.0 } else { 0.0 }),
+            }
+            .max(0.0);
+            let weight = children
+                .iter()
+                .fold(own_weight, |acc, child| acc + nodes[*child].weight);
+            for child in &children {
+                nodes[*child].parent = Some(ord);
+            }
+
+            ords.insert(node_id.clone(), ord);
+            nodes.push(WeightedNode {
+                node_id,
+                parent: None,
+                children,
+                own_weight,
+                weight,
+            });
+        }
+    }
+    nodes
+}
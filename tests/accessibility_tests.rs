@@ -0,0 +1,125 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree(texts: [&'static str; 3]) -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData(texts[0])), AsRoot)
+        .unwrap();
+    let child = tree
+        .insert(Node::new(MyNodeData(texts[1])), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(texts[2])), UnderNode(&child))
+        .unwrap();
+    tree
+}
+
+fn draw(drawer: &SvgDrawer, embedding: &Embedding, name: &str) -> String {
+    let file_name = std::env::temp_dir().join(name);
+    drawer.draw(&file_name, embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    svg
+}
+
+/// The description of the node with the given title.
+fn desc_of<'a>(svg: &'a str, title: &str) -> &'a str {
+    let title = svg
+        .find(format!("<title>{}</title>", title).as_str())
+        .unwrap();
+    let start = title + svg[title..].find("<desc>").unwrap() + "<desc>".len();
+    &svg[start..start + svg[start..].find("</desc>").unwrap()]
+}
+
+#[test]
+fn title_and_description() {
+    let embedding = Embedder::embed(&create_tree(["expr", "term", "x<1"]));
+    let plain = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_untitled.svg");
+    assert!(plain.contains("role=\"tree\""));
+    assert!(plain.find("<title>").unwrap() > plain.find("<g ").unwrap());
+
+    let drawer = SvgDrawer::new()
+        .with_title("Parse tree")
+        .with_description("A comparison & more");
+    let svg = draw(&drawer, &embedding, "id_tree_layout_titled.svg");
+
+    // The title and the description are the first children of the document
+    let svg_start = svg.find("<svg").unwrap();
+    let title = svg.find("<title>Parse tree</title>").unwrap();
+    let desc = svg.find("<desc>A comparison &amp; more</desc>").unwrap();
+    let first_node = svg.find("<g ").unwrap();
+    assert!(svg_start < title && title < desc && desc < first_node);
+    assert!(svg.contains("aria-hidden=\"true\""));
+}
+
+#[test]
+fn roles_levels_and_paths() {
+    let embedding = Embedder::embed(&create_tree(["expr", "term", "x<1"]));
+    let svg = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_aria.svg");
+
+    assert_eq!(3, svg.matches("role=\"treeitem\"").count());
+    assert_eq!(2, svg.matches("role=\"group\"").count());
+    assert!(svg.contains("role=\"treeitem\" aria-level=\"1\""));
+    assert!(svg.contains("role=\"treeitem\" aria-level=\"3\""));
+    assert_eq!("expr", desc_of(&svg, "expr"));
+    assert_eq!("expr / term / x&lt;1", desc_of(&svg, "x&lt;1"));
+
+    // The nodes are read from the root downwards
+    let expr = svg.find("<title>expr").unwrap();
+    let term = svg.find("<title>term").unwrap();
+    let leaf = svg.find("<title>x&lt;1").unwrap();
+    assert!(expr < term && term < leaf);
+}
+
+#[test]
+fn long_paths_are_elided() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let mut parent = tree.insert(Node::new(MyNodeData("top")), AsRoot).unwrap();
+    for _ in 0..20 {
+        parent = tree
+            .insert(Node::new(MyNodeData("n")), UnderNode(&parent))
+            .unwrap();
+    }
+    tree.insert(Node::new(MyNodeData("bottom")), UnderNode(&parent))
+        .unwrap();
+    let embedding = Embedder::embed(&tree);
+    let svg = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_elided.svg");
+
+    let desc = desc_of(&svg, "bottom");
+    assert!(desc.starts_with("top / … / n / "));
+    assert!(desc.ends_with(" / bottom"));
+    // 12 labels and the ellipsis
+    assert_eq!(13, desc.split(" / ").count());
+}
+
+#[test]
+fn captions_precede_their_trees() {
+    let trees = [
+        create_tree(["a", "b", "c"]),
+        create_tree(["d", "e", "f"]),
+        create_tree(["g", "h", "i"]),
+    ];
+    let embedding = Embedder::new()
+        .embed_forest(vec![
+            ForestTree::new(&trees[0]).with_caption("first".to_string()),
+            ForestTree::new(&trees[1]),
+            ForestTree::new(&trees[2]).with_caption("third".to_string()),
+        ])
+        .unwrap();
+    let svg = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_captions.svg");
+
+    let positions = [">first<", "<title>a<", "<title>d<", ">third<", "<title>g<"]
+        .iter()
+        .map(|text| svg.find(text).unwrap())
+        .collect::<Vec<usize>>();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+}
@@ -0,0 +1,97 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree() -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData("calc")), AsRoot).unwrap();
+    let assign = tree
+        .insert(Node::new(MyNodeData("assign")), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("x")), UnderNode(&assign))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("b")), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("c")), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+#[test]
+fn render_tree() {
+    let lines = AsciiDrawer::new().render(&Embedder::embed(&create_tree()));
+
+    assert_eq!(
+        vec![
+            "   calc",
+            "     |",
+            "   +-+--+-+",
+            "   |    | |",
+            "assign  b c",
+            "   |",
+            "   |",
+            "   |",
+            "   x",
+        ],
+        lines
+    );
+}
+
+#[test]
+fn render_forest_with_captions() {
+    let trees = [create_tree(), create_tree()];
+    let embedding = Embedder::new()
+        .embed_forest(
+            trees
+                .iter()
+                .zip(&["first", "second"])
+                .map(|(tree, caption)| ForestTree::new(tree).with_caption(caption.to_string())),
+        )
+        .unwrap();
+
+    let lines = AsciiDrawer::new().render(&embedding);
+
+    assert!(lines[0].trim_start().starts_with("first"));
+    assert!(lines[0].trim_end().ends_with("second"));
+    assert!(lines[1].is_empty());
+    assert_eq!(2, lines[2].matches("calc").count());
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_forest.txt");
+    AsciiDrawer::new().draw(&file_name, &embedding).unwrap();
+    let text = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    assert_eq!(lines.join("\n") + "\n", text);
+}
+
+#[test]
+fn render_aligned_leaves() {
+    let embedding = Embedder::new()
+        .with_aligned_leaves()
+        .embed_tree(&create_tree());
+
+    let lines = AsciiDrawer::new().render(&embedding);
+
+    // The leaves b and c are connected down to the level of x
+    assert_eq!(
+        vec![
+            "   calc",
+            "     |",
+            "   +-+--+-+",
+            "   |    | |",
+            "assign  | |",
+            "   |    | |",
+            "   |    | |",
+            "   |    | |",
+            "   x    b c",
+        ],
+        lines
+    );
+}
@@ -0,0 +1,171 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct Cluster(&'static str, f64);
+
+impl Visualize for Cluster {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+/// Creates the dendrogram of the clustering ((a, (b, c)), d).
+fn create_tree() -> Tree<Cluster> {
+    let mut tree: Tree<Cluster> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(Cluster("abcd", 8.0)), AsRoot)
+        .unwrap();
+    let abc = tree
+        .insert(Node::new(Cluster("abc", 4.0)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(Cluster("d", 0.0)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(Cluster("a", 0.0)), UnderNode(&abc))
+        .unwrap();
+    let bc = tree
+        .insert(Node::new(Cluster("bc", 1.0)), UnderNode(&abc))
+        .unwrap();
+    tree.insert(Node::new(Cluster("b", 0.0)), UnderNode(&bc))
+        .unwrap();
+    tree.insert(Node::new(Cluster("c", 0.0)), UnderNode(&bc))
+        .unwrap();
+    tree
+}
+
+fn y_order_of(embedding: &Embedding, text: &str) -> usize {
+    embedding
+        .iter()
+        .find(|item| item.text == text)
+        .unwrap()
+        .y_order
+}
+
+#[test]
+fn aligned_leaves() {
+    let tree = create_tree();
+
+    let embedding = Embedder::new().with_aligned_leaves().embed_tree(&tree);
+
+    for leaf in &["a", "b", "c", "d"] {
+        assert_eq!(3, y_order_of(&embedding, leaf));
+    }
+    assert_eq!(0, y_order_of(&embedding, "abcd"));
+    assert_eq!(1, y_order_of(&embedding, "abc"));
+    assert_eq!(2, y_order_of(&embedding, "bc"));
+    assert_eq!(4, embedding.depth());
+    assert_eq!(4, embedding.bounding_box().height);
+
+    // The horizontal layout is not affected
+    let plain = Embedder::embed(&tree);
+    for (item, plain_item) in embedding.iter().zip(plain.iter()) {
+        assert_eq!(plain_item.x_center, item.x_center);
+    }
+}
+
+#[test]
+fn merge_heights() {
+    let tree = create_tree();
+
+    let embedding = Embedder::new()
+        .with_merge_heights(|c: &Cluster| c.1, 9)
+        .embed_tree(&tree);
+
+    assert_eq!(0, y_order_of(&embedding, "abcd"));
+    assert_eq!(4, y_order_of(&embedding, "abc"));
+    assert_eq!(7, y_order_of(&embedding, "bc"));
+    for leaf in &["a", "b", "c", "d"] {
+        assert_eq!(9, y_order_of(&embedding, leaf));
+    }
+    assert_eq!(10, embedding.depth());
+    assert_eq!(10, embedding.bounding_box().height);
+}
+
+#[test]
+fn merge_heights_with_too_few_levels() {
+    let tree = create_tree();
+
+    let embedding = Embedder::new()
+        .with_merge_heights(|c: &Cluster| c.1, 2)
+        .embed_tree(&tree);
+
+    // Each internal node is at least one level below its parent
+    assert_eq!(0, y_order_of(&embedding, "abcd"));
+    assert_eq!(1, y_order_of(&embedding, "abc"));
+    assert_eq!(2, y_order_of(&embedding, "bc"));
+    for leaf in &["a", "b", "c", "d"] {
+        assert_eq!(3, y_order_of(&embedding, leaf));
+    }
+}
+
+#[test]
+fn single_node() {
+    let mut tree: Tree<Cluster> = TreeBuilder::new().build();
+    tree.insert(Node::new(Cluster("a", 0.0)), AsRoot).unwrap();
+
+    let embedding = Embedder::new().with_aligned_leaves().embed_tree(&tree);
+
+    assert_eq!(0, embedding[0].y_order);
+}
+
+#[test]
+fn single_node_with_merge_heights() {
+    let mut tree: Tree<Cluster> = TreeBuilder::new().build();
+    tree.insert(Node::new(Cluster("a", 0.0)), AsRoot).unwrap();
+
+    let embedding = Embedder::new()
+        .with_merge_heights(|c: &Cluster| c.1, 5)
+        .embed_tree(&tree);
+
+    assert_eq!(0, embedding[0].y_order);
+    assert_eq!(1, embedding.roots().count());
+    assert_eq!(1, embedding.bounding_box().height);
+}
+
+#[test]
+fn merge_heights_above_the_root() {
+    let mut tree: Tree<Cluster> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(Cluster("root", 2.0)), AsRoot)
+        .unwrap();
+    let inner = tree
+        .insert(Node::new(Cluster("inner", 10.0)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(Cluster("a", 0.0)), UnderNode(&inner))
+        .unwrap();
+    tree.insert(Node::new(Cluster("b", 0.0)), UnderNode(&inner))
+        .unwrap();
+    tree.insert(Node::new(Cluster("c", 0.0)), UnderNode(&root))
+        .unwrap();
+
+    let embedding = Embedder::new()
+        .with_merge_heights(|c: &Cluster| c.1, 4)
+        .embed_tree(&tree);
+
+    // The root stays on top, the node merged higher is placed right below it
+    assert_eq!(0, y_order_of(&embedding, "root"));
+    assert_eq!(1, y_order_of(&embedding, "inner"));
+    for leaf in &["a", "b", "c"] {
+        assert_eq!(4, y_order_of(&embedding, leaf));
+    }
+    assert_eq!(
+        vec!["root"],
+        embedding
+            .roots()
+            .map(|root| root.text.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(5, embedding.bounding_box().height);
+
+    let spacing = geometry::Spacing {
+        orientation: layout_options::Orientation::BottomUp,
+        ..geometry::Spacing::default()
+    };
+    let geometry = Geometry::new(
+        &embedding,
+        &geometry::MonospaceMeasurer::default(),
+        &spacing,
+    );
+    let root = embedding.root().unwrap().ord;
+    assert!(geometry.node(root).unwrap().rect.y > geometry.node(0).unwrap().rect.y);
+}
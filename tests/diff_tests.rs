@@ -0,0 +1,87 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::diff::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str, i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        format!("{}{}", self.0, self.1)
+    }
+}
+
+fn eq(o: &MyNodeData, n: &MyNodeData) -> bool {
+    o.0 == n.0
+}
+
+#[test]
+fn diff_of_trees() {
+    //      r               r
+    //    / | \            / \
+    //   a  b  c          a'  d
+    //      |             |   |
+    //      e             e   c
+    let mut old: Tree<MyNodeData> = TreeBuilder::new().build();
+    let r = old.insert(Node::new(MyNodeData("r", 0)), AsRoot).unwrap();
+    let a_old = old
+        .insert(Node::new(MyNodeData("a", 0)), UnderNode(&r))
+        .unwrap();
+    let b = old
+        .insert(Node::new(MyNodeData("b", 0)), UnderNode(&r))
+        .unwrap();
+    let c_old = old
+        .insert(Node::new(MyNodeData("c", 0)), UnderNode(&r))
+        .unwrap();
+    let e_old = old
+        .insert(Node::new(MyNodeData("e", 0)), UnderNode(&b))
+        .unwrap();
+
+    let mut new: Tree<MyNodeData> = TreeBuilder::new().build();
+    let r = new.insert(Node::new(MyNodeData("r", 0)), AsRoot).unwrap();
+    let a_new = new
+        .insert(Node::new(MyNodeData("a", 1)), UnderNode(&r))
+        .unwrap();
+    let d = new
+        .insert(Node::new(MyNodeData("d", 0)), UnderNode(&r))
+        .unwrap();
+    let e_new = new
+        .insert(Node::new(MyNodeData("e", 0)), UnderNode(&a_new))
+        .unwrap();
+    let c_new = new
+        .insert(Node::new(MyNodeData("c", 0)), UnderNode(&d))
+        .unwrap();
+
+    let statuses = diff_trees(&old, &new, eq);
+
+    assert_eq!(Some(&DiffStatus::Unchanged), statuses.get(&r));
+    assert_eq!(Some(&DiffStatus::Relabelled), statuses.get(&a_old));
+    assert_eq!(Some(&DiffStatus::Relabelled), statuses.get(&a_new));
+    assert_eq!(Some(&DiffStatus::Deleted), statuses.get(&b));
+    assert_eq!(Some(&DiffStatus::Moved), statuses.get(&c_old));
+    assert_eq!(Some(&DiffStatus::Moved), statuses.get(&c_new));
+    assert_eq!(Some(&DiffStatus::Inserted), statuses.get(&d));
+    assert_eq!(Some(&DiffStatus::Moved), statuses.get(&e_old));
+    assert_eq!(Some(&DiffStatus::Moved), statuses.get(&e_new));
+
+    let embedding = Embedder::new().embed_diff(&old, &new, eq);
+
+    assert_eq!(10, embedding.len());
+    assert_eq!(2, embedding.captions().len());
+    assert!(embedding
+        .iter()
+        .all(|e| embedding.diff_status(e.ord).is_some()));
+    let deleted = embedding.iter().find(|e| e.text == "b0").unwrap();
+    assert_eq!(
+        Some(DiffStatus::Deleted),
+        embedding.diff_status(deleted.ord)
+    );
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_diff.svg");
+    SvgDrawer::new().draw(&file_name, &embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    assert!(svg.contains("line-through"));
+    assert!(svg.contains("class=\"deleted\""));
+    assert!(svg.contains("fill=\"green\""));
+    let _ = std::fs::remove_file(file_name);
+}
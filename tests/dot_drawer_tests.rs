@@ -0,0 +1,71 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn emphasize(&self) -> bool {
+        self.0.starts_with('"')
+    }
+
+    fn url(&self) -> Option<String> {
+        Some(format!("https://example.com/{}", self.0.len()))
+    }
+}
+
+fn create_tree(texts: [&'static str; 3]) -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData(texts[0])), AsRoot)
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(texts[1])), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(texts[2])), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+fn draw(embedding: &Embedding, name: &str) -> String {
+    let file_name = std::env::temp_dir().join(name);
+    DotDrawer::new().draw(&file_name, embedding).unwrap();
+    let dot = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    dot
+}
+
+#[test]
+fn nodes_and_edges_in_pre_order() {
+    let embedding = Embedder::embed(&create_tree(["root", "\"quoted\"", "a\\b"]));
+    let dot = draw(&embedding, "id_tree_layout_tree.dot");
+
+    assert!(dot.starts_with("digraph tree {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert!(dot.contains("n2 [label=\"root\", URL=\"https://example.com/4\"];"));
+    assert!(dot.contains(
+        "n0 [label=\"\\\"quoted\\\"\", fontname=\"Courier-Bold\", URL=\"https://example.com/8\"];"
+    ));
+    assert!(dot.contains("n1 [label=\"a\\\\b\""));
+    let root = dot.find("n2 [").unwrap();
+    let first = dot.find("n2 -> n0;").unwrap();
+    let second = dot.find("n2 -> n1;").unwrap();
+    assert!(root < first && first < second);
+}
+
+#[test]
+fn diff_in_clusters() {
+    let old = create_tree(["root", "a", "b"]);
+    let new = create_tree(["root", "a", "c"]);
+    let embedding = Embedder::new().embed_diff(&old, &new, |o, n| o.0 == n.0);
+    let dot = draw(&embedding, "id_tree_layout_diff.dot");
+
+    assert!(dot.contains("subgraph cluster_0 {"));
+    assert!(dot.contains("label=\"old\";"));
+    assert!(dot.contains("label=\"new\";"));
+    assert!(dot.contains("[label=\"b\", fontcolor=\"red\""));
+    assert!(dot.contains("[label=\"c\", fontcolor=\"green\""));
+}
@@ -179,3 +179,254 @@ fn moved_nodes() {
         assert_eq!(2, e.x_extent_children);
     }
 }
+
+#[test]
+fn compressed_unary_chains() {
+    //      0
+    //     / \
+    //    1   2
+    //    |   |
+    //    3   4
+    //    |  / \
+    //    5 6   7
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(8).build();
+
+    let n0 = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&n0))
+        .unwrap();
+    let n2 = tree
+        .insert(Node::new(MyNodeData(2)), UnderNode(&n0))
+        .unwrap();
+    let n3 = tree
+        .insert(Node::new(MyNodeData(3)), UnderNode(&n1))
+        .unwrap();
+    let n4 = tree
+        .insert(Node::new(MyNodeData(4)), UnderNode(&n2))
+        .unwrap();
+    let n5 = tree
+        .insert(Node::new(MyNodeData(5)), UnderNode(&n3))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(6)), UnderNode(&n4))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(7)), UnderNode(&n4))
+        .unwrap();
+
+    let embedding = Embedder::new()
+        .with_chain_compression(ChainCompression::Joined("/".to_string()))
+        .embed_tree(&tree);
+
+    assert_eq!(5, embedding.len());
+
+    let texts = embedding
+        .iter()
+        .map(|e| e.text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["1/3/5", "6", "7", "2/4", "0"], texts);
+    {
+        let e = &embedding[0];
+        assert_eq!(0, e.ord);
+        assert_eq!(Some(4), e.parent);
+        assert_eq!(1, e.y_order);
+        assert_eq!(6, e.x_extent);
+        assert_eq!(vec![n1.clone(), n3, n5], e.chain);
+    }
+    {
+        let e = &embedding[3];
+        assert_eq!(3, e.ord);
+        assert_eq!(Some(4), e.parent);
+        assert_eq!(1, e.y_order);
+        assert_eq!(4, e.x_extent);
+        assert_eq!(4, e.x_extent_children);
+        assert_eq!(vec![n2, n4], e.chain);
+    }
+    {
+        let e = &embedding[1];
+        assert_eq!(Some(3), e.parent);
+        assert_eq!(2, e.y_order);
+        assert!(e.chain.is_empty());
+    }
+    {
+        let e = &embedding[4];
+        assert_eq!(None, e.parent);
+        assert_eq!(0, e.y_order);
+        assert_eq!(10, e.x_extent_children);
+        assert!(e.chain.is_empty());
+    }
+}
+
+#[test]
+fn stacked_unary_chains() {
+    //    0
+    //    |
+    //   10
+    //    |
+    //   200
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(3).build();
+
+    let n0 = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(10)), UnderNode(&n0))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(200)), UnderNode(&n1))
+        .unwrap();
+
+    let embedding = Embedder::new()
+        .with_chain_compression(ChainCompression::Stacked)
+        .embed_tree(&tree);
+
+    assert_eq!(1, embedding.len());
+    let e = &embedding[0];
+    assert_eq!("0\n10\n200", e.text);
+    assert_eq!(4, e.x_extent);
+    assert_eq!(2, e.x_center);
+    assert_eq!(3, e.chain.len());
+}
+
+#[test]
+fn hidden_nodes() {
+    //      0
+    //     / \
+    //   -1   2
+    //   / \   \
+    //  3  -4   5
+    //     / \
+    //    6   7
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(8).build();
+
+    let n0 = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(-1)), UnderNode(&n0))
+        .unwrap();
+    let n2 = tree
+        .insert(Node::new(MyNodeData(2)), UnderNode(&n0))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(3)), UnderNode(&n1))
+        .unwrap();
+    let n4 = tree
+        .insert(Node::new(MyNodeData(-4)), UnderNode(&n1))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(5)), UnderNode(&n2))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(6)), UnderNode(&n4))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(7)), UnderNode(&n4))
+        .unwrap();
+
+    let embedding = Embedder::new()
+        .with_hidden_nodes(|n: &MyNodeData| n.0 < 0)
+        .embed_tree(&tree);
+
+    //        0
+    //    / / | \
+    //   3 6  7  2
+    //           |
+    //           5
+    let texts = embedding
+        .iter()
+        .map(|e| e.text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["3", "6", "7", "5", "2", "0"], texts);
+    for e in embedding.iter().filter(|e| e.text != "0" && e.text != "5") {
+        assert_eq!(Some(5), e.parent);
+        assert_eq!(1, e.y_order);
+    }
+    assert_eq!(Some(4), embedding[3].parent);
+    assert_eq!(2, embedding[3].y_order);
+    assert_eq!(
+        vec![1, 3, 5, 7],
+        embedding[..4]
+            .iter()
+            .map(|e| e.x_center)
+            .collect::<Vec<_>>()
+    );
+
+    let embedding = Embedder::new()
+        .with_hidden_nodes(|n: &MyNodeData| n.0 < 0)
+        .with_chain_compression(ChainCompression::Joined(".".to_string()))
+        .embed_tree(&tree);
+
+    let texts = embedding
+        .iter()
+        .map(|e| e.text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["3", "6", "7", "2.5", "0"], texts);
+}
+
+#[test]
+fn node_ids_of_items() {
+    //      0
+    //     / \
+    //    1   2
+    //    |
+    //    3
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(4).build();
+
+    let n0 = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&n0))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(2)), UnderNode(&n0))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(3)), UnderNode(&n1))
+        .unwrap();
+
+    let embedding = Embedder::embed(&tree);
+
+    assert_eq!(4, embedding.len());
+    for e in &embedding {
+        let node_id = e.node_id.as_ref().unwrap();
+        assert_eq!(e.text, tree.get(node_id).unwrap().data().0.to_string());
+    }
+
+    let embedding = Embedder::new()
+        .with_chain_compression(ChainCompression::Stacked)
+        .embed_tree(&tree);
+
+    assert_eq!(3, embedding.len());
+    assert_eq!(Some(n1), embedding[0].node_id);
+    assert_eq!(Some(n0), embedding[2].node_id);
+}
+
+struct IconData(&'static str, Option<usize>);
+
+impl Visualize for IconData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn width_hint(&self) -> Option<usize> {
+        self.1
+    }
+
+    fn height_hint(&self) -> Option<usize> {
+        self.1.map(|_| 2)
+    }
+}
+
+#[test]
+fn size_hints() {
+    //      root
+    //     /    \
+    //  icon    text
+    let mut tree: Tree<IconData> = TreeBuilder::new().with_node_capacity(3).build();
+
+    let root = tree
+        .insert(Node::new(IconData("root", None)), AsRoot)
+        .unwrap();
+    tree.insert(Node::new(IconData("icon", Some(9))), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(IconData("text", None)), UnderNode(&root))
+        .unwrap();
+
+    let embedding = Embedder::embed(&tree);
+
+    assert_eq!(10, embedding[0].x_extent);
+    assert_eq!(Some(9), embedding[0].width_hint);
+    assert_eq!(Some(2), embedding[0].height_hint);
+    assert_eq!(5, embedding[0].x_center);
+    assert_eq!(5, embedding[1].x_extent);
+    assert_eq!(None, embedding[1].width_hint);
+    assert_eq!(12, embedding[1].x_center);
+    assert_eq!(15, embedding[2].x_extent_children);
+}
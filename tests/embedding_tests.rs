@@ -0,0 +1,164 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+use std::convert::TryFrom;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree() -> Tree<MyNodeData> {
+    //      0
+    //     / \
+    //    1   2
+    //   / \
+    //  3   4
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(5).build();
+
+    let root_id: NodeId = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let child_id: NodeId = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(2)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(3)), UnderNode(&child_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(4)), UnderNode(&child_id))
+        .unwrap();
+    tree
+}
+
+fn texts<'a>(items: impl Iterator<Item = &'a PlacedTreeItem>) -> Vec<&'a str> {
+    items.map(|e| e.text.as_str()).collect()
+}
+
+#[test]
+fn empty_embedding() {
+    let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+
+    let embedding = Embedder::embed(&tree);
+
+    assert!(embedding.root().is_none());
+    assert!(embedding.parent(0).is_none());
+    assert_eq!(0, embedding.children(0).count());
+    assert_eq!(0, embedding.depth());
+    assert_eq!(BoundingBox::default(), embedding.bounding_box());
+}
+
+#[test]
+fn navigation() {
+    let tree = create_tree();
+
+    let embedding = Embedder::embed(&tree);
+
+    for (index, e) in embedding.iter().enumerate() {
+        assert_eq!(index, e.ord);
+    }
+    assert_eq!("0", embedding.root().unwrap().text);
+    assert_eq!("1", embedding.parent(0).unwrap().text);
+    assert!(embedding.parent(4).is_none());
+    assert_eq!(vec!["1", "2"], texts(embedding.children(4)));
+    assert_eq!(vec!["3", "4"], texts(embedding.children(2)));
+    assert_eq!(0, embedding.children(3).count());
+
+    assert_eq!(3, embedding.depth());
+    assert_eq!(vec!["0"], texts(embedding.level(0)));
+    assert_eq!(vec!["1", "2"], texts(embedding.level(1)));
+    assert_eq!(vec!["3", "4"], texts(embedding.level(2)));
+    assert_eq!(0, embedding.level(3).count());
+}
+
+#[test]
+fn bounding_boxes() {
+    let tree = create_tree();
+
+    let embedding = Embedder::embed(&tree);
+
+    assert_eq!(
+        BoundingBox {
+            x: 0,
+            y: 0,
+            width: 6,
+            height: 3
+        },
+        embedding.bounding_box()
+    );
+    assert_eq!(
+        BoundingBox {
+            x: 0,
+            y: 1,
+            width: 4,
+            height: 2
+        },
+        embedding.subtree_bounding_box(2)
+    );
+    assert_eq!(
+        BoundingBox {
+            x: 4,
+            y: 1,
+            width: 2,
+            height: 1
+        },
+        embedding.subtree_bounding_box(3)
+    );
+}
+
+#[test]
+fn embedding_from_items() {
+    let tree = create_tree();
+
+    let mut items = Embedder::embed(&tree).into_iter().collect::<Vec<_>>();
+    items.reverse();
+    let embedding = Embedding::try_from(items).unwrap();
+
+    assert_eq!(vec!["3", "4", "1", "2", "0"], texts(embedding.iter()));
+    assert_eq!(vec!["3", "4"], texts(embedding.children(2)));
+}
+
+#[test]
+fn invalid_items() {
+    let items = Embedder::embed(&create_tree())
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut duplicated = items.clone();
+    duplicated[1].ord = 0;
+    assert_eq!(
+        EmbeddingError::InvalidOrd(0),
+        Embedding::try_from(duplicated).unwrap_err()
+    );
+
+    let mut gap = items.clone();
+    gap.pop();
+    gap[0].ord = 5;
+    assert!(matches!(
+        Embedding::try_from(gap),
+        Err(EmbeddingError::InvalidOrd(_))
+    ));
+
+    let mut cycle = items.clone();
+    cycle[4].parent = Some(0);
+    assert_eq!(
+        EmbeddingError::InvalidParent { ord: 4, parent: 0 },
+        Embedding::try_from(cycle).unwrap_err()
+    );
+
+    let mut dangling = items.clone();
+    dangling[0].parent = Some(5);
+    assert_eq!(
+        EmbeddingError::InvalidParent { ord: 0, parent: 5 },
+        Embedding::try_from(dangling).unwrap_err()
+    );
+
+    let mut outside = items;
+    outside[0].x_center = 0;
+    outside[0].x_extent = 3;
+    assert_eq!(
+        EmbeddingError::InvalidExtent(0),
+        Embedding::try_from(outside).unwrap_err()
+    );
+}
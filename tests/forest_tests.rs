@@ -0,0 +1,114 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree(offset: i32) -> Tree<MyNodeData> {
+    //      0
+    //     / \
+    //    1   2
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(3).build();
+
+    let root_id: NodeId = tree.insert(Node::new(MyNodeData(offset)), AsRoot).unwrap();
+    tree.insert(Node::new(MyNodeData(offset + 1)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(offset + 2)), UnderNode(&root_id))
+        .unwrap();
+    tree
+}
+
+#[test]
+fn forest_of_trees() {
+    let trees = [create_tree(0), create_tree(3)];
+
+    let embedding = Embedder::new()
+        .with_options(LayoutOptions {
+            tree_gap: 3,
+            ..Default::default()
+        })
+        .embed_forest(trees.iter().map(ForestTree::from))
+        .unwrap();
+
+    assert_eq!(6, embedding.len());
+    let roots = embedding.roots().collect::<Vec<_>>();
+    assert_eq!(2, roots.len());
+    assert_eq!("0", roots[0].text);
+    assert_eq!(2, roots[0].ord);
+    assert_eq!(2, roots[0].x_center);
+    assert_eq!("3", roots[1].text);
+    assert_eq!(5, roots[1].ord);
+    assert_eq!(9, roots[1].x_center);
+    assert_eq!(
+        vec![3, 4],
+        embedding.children(5).map(|e| e.ord).collect::<Vec<_>>()
+    );
+    assert!(embedding.captions().is_empty());
+    assert_eq!(
+        BoundingBox {
+            x: 0,
+            y: 0,
+            width: 11,
+            height: 2
+        },
+        embedding.bounding_box()
+    );
+}
+
+#[test]
+fn forest_with_captions_and_subtrees() {
+    let tree1 = create_tree(0);
+    let tree2 = create_tree(3);
+    let subtree_root = tree2
+        .children_ids(tree2.root_node_id().unwrap())
+        .unwrap()
+        .next()
+        .unwrap()
+        .clone();
+
+    let embedding = Embedder::new()
+        .embed_forest(vec![
+            ForestTree::new(&tree1).with_caption("first tree".to_string()),
+            ForestTree::new(&tree2)
+                .with_root(subtree_root)
+                .with_caption("x".to_string()),
+        ])
+        .unwrap();
+
+    assert_eq!(4, embedding.len());
+    let captions = embedding.captions();
+    assert_eq!(2, captions.len());
+    assert_eq!(11, captions[0].x_extent);
+    assert_eq!(5, captions[0].x_center);
+    assert_eq!(2, captions[1].x_extent);
+    assert_eq!(14, captions[1].x_center);
+    // The first tree is centered under its wider caption
+    assert_eq!(5, embedding[2].x_center);
+    assert_eq!("4", embedding[3].text);
+    assert_eq!(14, embedding[3].x_center);
+    assert_eq!(15, embedding.bounding_box().width);
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_forest.svg");
+    SvgDrawer::new().draw(&file_name, &embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    assert!(svg.contains("first tree"));
+    let _ = std::fs::remove_file(file_name);
+}
+
+#[test]
+fn forest_with_invalid_subtree_root() {
+    let tree1 = create_tree(0);
+    let tree2 = create_tree(3);
+
+    let result = Embedder::new().embed_forest(vec![
+        ForestTree::new(&tree1).with_root(tree2.root_node_id().unwrap().clone())
+    ]);
+
+    assert!(result.is_err());
+}
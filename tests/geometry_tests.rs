@@ -0,0 +1,195 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::geometry::*;
+use id_tree_layout::*;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn geometry_of_tree() {
+    //      0
+    //     / \
+    //    1   22
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(3).build();
+
+    let root_id: NodeId = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    tree.insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(22)), UnderNode(&root_id))
+        .unwrap();
+
+    let embedding = Embedder::embed(&tree);
+    let measurer = MonospaceMeasurer {
+        char_width: 2.0,
+        line_height: 4.0,
+    };
+    let spacing = Spacing {
+        unit_width: 2.0,
+        level_height: 10.0,
+        margin_x: 1.0,
+        margin_y: 5.0,
+        edge_gap: 0.5,
+        ..Default::default()
+    };
+    let geometry = Geometry::new(&embedding, &measurer, &spacing);
+
+    assert_eq!(
+        Size {
+            width: 11.0,
+            height: 25.0
+        },
+        geometry.size()
+    );
+
+    // Node "22" with x_center 3 on level 1
+    let node = geometry.node(1).unwrap();
+    assert_eq!(
+        Rect {
+            x: 5.0,
+            y: 11.0,
+            width: 4.0,
+            height: 4.0
+        },
+        node.rect
+    );
+    assert_eq!(Point { x: 7.0, y: 11.0 }, node.top_anchor);
+    assert_eq!(Point { x: 7.0, y: 15.5 }, node.bottom_anchor);
+
+    let edges = geometry.edges().collect::<Vec<_>>();
+    assert_eq!(2, edges.len());
+    assert_eq!(2, edges[1].parent);
+    assert_eq!(1, edges[1].child);
+    assert_eq!(geometry.node(2).unwrap().bottom_anchor, edges[1].from);
+    assert_eq!(node.top_anchor, edges[1].to);
+    assert!(geometry.edge_to(2).is_none());
+}
+
+#[test]
+fn multi_line_texts() {
+    let measurer = MonospaceMeasurer::default();
+
+    assert_eq!(
+        Size {
+            width: 30.0,
+            height: 20.0
+        },
+        measurer.measure("ab\nabc")
+    );
+}
+
+#[test]
+fn rect_queries() {
+    let rect = Rect {
+        x: 1.0,
+        y: 1.0,
+        width: 2.0,
+        height: 2.0,
+    };
+
+    assert!(rect.contains(Point { x: 3.0, y: 2.0 }));
+    assert!(!rect.contains(Point { x: 3.5, y: 2.0 }));
+    assert!(rect.intersects(&Rect {
+        x: 2.0,
+        y: 0.0,
+        width: 5.0,
+        height: 1.5
+    }));
+    assert!(!rect.intersects(&Rect {
+        x: 4.0,
+        y: 0.0,
+        width: 5.0,
+        height: 1.5
+    }));
+}
+
+struct IconData(Option<usize>, Option<usize>);
+
+impl Visualize for IconData {
+    fn visualize(&self) -> std::string::String {
+        "icon".to_string()
+    }
+
+    fn width_hint(&self) -> Option<usize> {
+        self.0
+    }
+
+    fn height_hint(&self) -> Option<usize> {
+        self.1
+    }
+}
+
+#[test]
+fn geometry_with_size_hints() {
+    let mut tree: Tree<IconData> = TreeBuilder::new().with_node_capacity(2).build();
+
+    let root_id: NodeId = tree
+        .insert(Node::new(IconData(Some(6), Some(3))), AsRoot)
+        .unwrap();
+    tree.insert(Node::new(IconData(None, None)), UnderNode(&root_id))
+        .unwrap();
+
+    let embedding = Embedder::embed(&tree);
+    let geometry = Geometry::new(
+        &embedding,
+        &MonospaceMeasurer::default(),
+        &Spacing::default(),
+    );
+
+    let root = geometry.node(1).unwrap();
+    assert_eq!(60.0, root.rect.width);
+    assert_eq!(30.0, root.rect.height);
+    let child = geometry.node(0).unwrap();
+    assert_eq!(40.0, child.rect.width);
+    assert_eq!(10.0, child.rect.height);
+    // The level of the root is extended by its additional lines
+    assert_eq!(
+        root.rect.y + 30.0 + Spacing::default().level_height,
+        child.rect.y + 10.0
+    );
+}
+
+struct Rule(&'static str);
+
+impl Visualize for Rule {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn stacked_chain_extends_its_level() {
+    let mut tree: Tree<Rule> = TreeBuilder::new().build();
+    let mut parent = tree.insert(Node::new(Rule("Expr")), AsRoot).unwrap();
+    for label in &["Term", "Factor", "Primary", "Literal"] {
+        parent = tree
+            .insert(Node::new(Rule(label)), UnderNode(&parent))
+            .unwrap();
+    }
+    tree.insert(Node::new(Rule("1")), UnderNode(&parent))
+        .unwrap();
+    tree.insert(Node::new(Rule("2")), UnderNode(&parent))
+        .unwrap();
+
+    let embedding = Embedder::new()
+        .with_chain_compression(ChainCompression::Stacked)
+        .embed_tree(&tree);
+    let spacing = Spacing::default();
+    let geometry = Geometry::new(&embedding, &MonospaceMeasurer::default(), &spacing);
+
+    let chain = embedding.root().unwrap();
+    assert!(chain.text.split('\n').count() > 3);
+    let chain_rect = geometry.node(chain.ord).unwrap().rect;
+    for child in embedding.children(chain.ord) {
+        let child_rect = geometry.node(child.ord).unwrap().rect;
+        assert!(chain_rect.bottom() + spacing.edge_gap < child_rect.y);
+        let edge = geometry.edge_to(child.ord).unwrap();
+        assert!(edge.from.y < edge.to.y);
+        assert!(child_rect.bottom() <= geometry.size().height);
+    }
+}
@@ -0,0 +1,37 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn page_with_svg_and_script() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData("root")), AsRoot).unwrap();
+    tree.insert(Node::new(MyNodeData("leaf")), UnderNode(&root))
+        .unwrap();
+    let embedding = Embedder::embed(&tree);
+    let drawer = HtmlDrawer::new().with_drawer(SvgDrawer::new().with_title("A < B"));
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_page.html");
+    drawer.draw(&file_name, &embedding).unwrap();
+    let html = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>A &lt; B</title>"));
+    // The Svg is embedded without the XML declaration and precedes the script
+    assert!(!html.contains("<?xml"));
+    let svg = html.find("<svg").unwrap();
+    let svg_end = html.find("</svg>").unwrap();
+    let script = html.find("<script>").unwrap();
+    assert!(svg < svg_end && svg_end < script);
+    assert!(html.contains("aria-expanded"));
+    assert!(html.trim_end().ends_with("</html>"));
+}
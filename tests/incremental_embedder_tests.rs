@@ -0,0 +1,248 @@
+use id_tree::InsertBehavior::*;
+use id_tree::MoveBehavior::*;
+use id_tree::RemoveBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree() -> (Tree<MyNodeData>, Vec<NodeId>) {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&root))
+        .unwrap();
+    let n2 = tree
+        .insert(Node::new(MyNodeData(2)), UnderNode(&root))
+        .unwrap();
+    let n3 = tree
+        .insert(Node::new(MyNodeData(3)), UnderNode(&n1))
+        .unwrap();
+    let n4 = tree
+        .insert(Node::new(MyNodeData(4)), UnderNode(&n1))
+        .unwrap();
+    let n5 = tree
+        .insert(Node::new(MyNodeData(5)), UnderNode(&n2))
+        .unwrap();
+    (tree, vec![root, n1, n2, n3, n4, n5])
+}
+
+/// Compares the items and the navigation helpers of two embeddings.
+fn assert_same(expected: &Embedding, actual: &Embedding) {
+    assert_eq!(expected[..], actual[..]);
+    assert_eq!(expected.depth(), actual.depth());
+    for y_order in 0..expected.depth() {
+        let expected_level = expected.level(y_order).map(|item| item.ord);
+        let actual_level = actual.level(y_order).map(|item| item.ord);
+        assert!(expected_level.eq(actual_level));
+    }
+    for item in expected.iter() {
+        let expected_children = expected.children(item.ord).map(|child| child.ord);
+        assert!(expected_children.eq(actual.children(item.ord).map(|child| child.ord)));
+        assert_eq!(
+            expected.subtree_bounding_box(item.ord),
+            actual.subtree_bounding_box(item.ord)
+        );
+    }
+    assert_eq!(expected.bounding_box(), actual.bounding_box());
+}
+
+#[test]
+fn initial_embedding() {
+    let (tree, _) = create_tree();
+
+    let mut embedder = IncrementalEmbedder::new();
+
+    assert_same(&Embedder::embed(&tree), embedder.embed(&tree));
+}
+
+#[test]
+fn empty_tree() {
+    let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+
+    let mut embedder = IncrementalEmbedder::new();
+
+    assert!(embedder.embed(&tree).is_empty());
+    assert!(embedder.update(&tree, &[]).is_empty());
+}
+
+#[test]
+fn changed_data() {
+    let (mut tree, ids) = create_tree();
+    let mut embedder = IncrementalEmbedder::new();
+    embedder.embed(&tree);
+
+    tree.get_mut(&ids[3]).unwrap().data_mut().0 = 12345;
+    let embedding = embedder.update(&tree, &[ids[3].clone()]);
+
+    assert_same(&Embedder::embed(&tree), embedding);
+}
+
+#[test]
+fn inserted_node() {
+    let (mut tree, ids) = create_tree();
+    let mut embedder = IncrementalEmbedder::new();
+    embedder.embed(&tree);
+
+    let n6 = tree
+        .insert(Node::new(MyNodeData(666666)), UnderNode(&ids[4]))
+        .unwrap();
+    let embedding = embedder.update(&tree, &[n6]);
+
+    assert_same(&Embedder::embed(&tree), embedding);
+}
+
+#[test]
+fn removed_node() {
+    let (mut tree, ids) = create_tree();
+    let mut embedder = IncrementalEmbedder::new();
+    embedder.embed(&tree);
+
+    tree.remove_node(ids[1].clone(), DropChildren).unwrap();
+    let embedding = embedder.update(&tree, &[ids[0].clone()]);
+
+    assert_same(&Embedder::embed(&tree), embedding);
+}
+
+#[test]
+fn moved_node() {
+    let (mut tree, ids) = create_tree();
+    let mut embedder = IncrementalEmbedder::new();
+    embedder.embed(&tree);
+
+    tree.move_node(&ids[1], ToParent(&ids[5])).unwrap();
+    let embedding = embedder.update(&tree, &[ids[0].clone(), ids[5].clone()]);
+
+    assert_same(&Embedder::embed(&tree), embedding);
+}
+
+#[test]
+fn sequence_of_changes() {
+    let (mut tree, ids) = create_tree();
+    let mut embedder = IncrementalEmbedder::new();
+    embedder.embed(&tree);
+
+    for i in 0..20 {
+        let parent = &ids[i % ids.len()];
+        let node = tree
+            .insert(Node::new(MyNodeData(i as i32 * 1000)), UnderNode(parent))
+            .unwrap();
+        let embedding = embedder.update(&tree, std::slice::from_ref(&node));
+        assert_same(&Embedder::embed(&tree), embedding);
+
+        if i % 3 == 0 {
+            tree.remove_node(node, DropChildren).unwrap();
+            let embedding = embedder.update(&tree, std::slice::from_ref(parent));
+            assert_same(&Embedder::embed(&tree), embedding);
+        }
+    }
+}
+
+struct IconData(i32, Option<usize>);
+
+impl Visualize for IconData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn width_hint(&self) -> Option<usize> {
+        self.1
+    }
+}
+
+#[test]
+fn changed_size_hint() {
+    let mut tree: Tree<IconData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(IconData(0, None)), AsRoot).unwrap();
+    let child = tree
+        .insert(Node::new(IconData(1, Some(8))), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(IconData(2, None)), UnderNode(&root))
+        .unwrap();
+    let mut embedder = IncrementalEmbedder::new();
+    assert_same(&Embedder::embed(&tree), embedder.embed(&tree));
+
+    tree.get_mut(&child).unwrap().data_mut().1 = Some(3);
+    let embedding = embedder.update(&tree, std::slice::from_ref(&child));
+
+    assert_same(&Embedder::embed(&tree), embedding);
+}
+
+/// A pseudo random number generator to change a tree reproducibly.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % bound as u64) as usize
+    }
+}
+
+#[test]
+fn random_changes() {
+    for alignment in &[
+        layout_options::Alignment::Center,
+        layout_options::Alignment::Left,
+    ] {
+        let options = LayoutOptions {
+            alignment: *alignment,
+            ..LayoutOptions::default()
+        };
+        let mut random = Random(42);
+        let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+        let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+        let mut ids = vec![root.clone()];
+        for i in 1..200 {
+            let parent = ids[random.next(ids.len())].clone();
+            ids.push(
+                tree.insert(Node::new(MyNodeData(i)), UnderNode(&parent))
+                    .unwrap(),
+            );
+        }
+        let mut embedder = IncrementalEmbedder::new().with_options(options);
+        embedder.embed(&tree);
+
+        for i in 0..300 {
+            let node = ids[random.next(ids.len())].clone();
+            let changed = match random.next(4) {
+                0 => {
+                    tree.get_mut(&node).unwrap().data_mut().0 = 10i32.pow(i % 6);
+                    vec![node]
+                }
+                1 => {
+                    let child = tree
+                        .insert(Node::new(MyNodeData(i as i32)), UnderNode(&node))
+                        .unwrap();
+                    ids.push(child.clone());
+                    vec![child]
+                }
+                2 if node != root => {
+                    let parent = tree.get(&node).unwrap().parent().unwrap().clone();
+                    tree.remove_node(node.clone(), LiftChildren).unwrap();
+                    ids.retain(|id| *id != node);
+                    vec![parent]
+                }
+                _ => {
+                    let target = ids[random.next(ids.len())].clone();
+                    if target == node || tree.ancestor_ids(&target).unwrap().any(|id| *id == node) {
+                        continue;
+                    }
+                    let parent = tree.get(&node).unwrap().parent().cloned();
+                    tree.move_node(&node, ToParent(&target)).unwrap();
+                    parent.into_iter().chain(Some(target)).collect()
+                }
+            };
+            let expected = Embedder::new().with_options(options).embed_tree(&tree);
+            assert_same(&expected, embedder.update(&tree, &changed));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "serde_support")]
+
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn tooltip(&self) -> Option<String> {
+        Some(format!("{} chars", self.0.len()))
+    }
+}
+
+fn create_tree(texts: [&'static str; 3]) -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData(texts[0])), AsRoot)
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(texts[1])), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(texts[2])), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+fn export(embedding: &Embedding, name: &str) -> serde_json::Value {
+    let file_name = std::env::temp_dir().join(name);
+    JsonDrawer::new().draw(&file_name, embedding).unwrap();
+    let json = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn export_tree() {
+    let embedding = Embedder::embed(&create_tree(["root", "a", "b"]));
+    let json = export(&embedding, "id_tree_layout_tree.json");
+
+    let bounding_box = embedding.bounding_box();
+    assert_eq!(bounding_box.width, json["width"]);
+    assert_eq!(2, json["height"]);
+    assert_eq!(0, json["captions"].as_array().unwrap().len());
+    let items = json["items"].as_array().unwrap();
+    assert_eq!(3, items.len());
+    for (item, exported) in embedding.iter().zip(items) {
+        assert_eq!(item.ord, exported["ord"]);
+        assert_eq!(item.x_center, exported["x_center"]);
+        assert_eq!(item.text, exported["text"]);
+    }
+    assert_eq!(serde_json::json!([0, 1]), items[2]["children"]);
+    assert_eq!(2, items[0]["parent"]);
+    assert_eq!("4 chars", items[2]["tooltip"]);
+    assert!(items[2].get("url").is_none());
+    assert!(items[2].get("diff_status").is_none());
+}
+
+#[test]
+fn export_diff() {
+    let old = create_tree(["root", "a", "b"]);
+    let new = create_tree(["root", "a", "c"]);
+    let embedding = Embedder::new().embed_diff(&old, &new, |o, n| o.0 == n.0);
+    let json = export(&embedding, "id_tree_layout_diff.json");
+
+    assert_eq!("old", json["captions"][0]["text"]);
+    let items = json["items"].as_array().unwrap();
+    let status_of = |text: &str| {
+        items
+            .iter()
+            .find(|item| item["text"] == text)
+            .map(|item| item["diff_status"].clone())
+            .unwrap()
+    };
+    assert_eq!("deleted", status_of("b"));
+    assert_eq!("inserted", status_of("c"));
+}
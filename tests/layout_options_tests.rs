@@ -0,0 +1,207 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::layout_options::{Alignment, Orientation};
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+///
+/// Creates the tree
+/// root -> [left -> [a, b], c]
+///
+fn create_tree() -> (Tree<MyNodeData>, NodeId) {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData("root")), AsRoot).unwrap();
+    let left = tree
+        .insert(Node::new(MyNodeData("left")), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("a")), UnderNode(&left))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("b")), UnderNode(&left))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("c")), UnderNode(&root))
+        .unwrap();
+    (tree, left)
+}
+
+#[test]
+fn default_options_change_nothing() {
+    let (tree, _) = create_tree();
+
+    let embedding = Embedder::new()
+        .with_options(LayoutOptions::default())
+        .embed_tree(&tree);
+
+    assert_eq!(Embedder::embed(&tree)[..], embedding[..]);
+    assert_eq!(
+        SvgDrawer::new().geometry(&embedding).nodes(),
+        SvgDrawer::new()
+            .with_options(LayoutOptions::default())
+            .geometry(&embedding)
+            .nodes()
+    );
+}
+
+#[test]
+fn horizontal_gaps() {
+    let (tree, _) = create_tree();
+
+    let embedding = Embedder::new()
+        .with_options(LayoutOptions {
+            sibling_gap: 2,
+            subtree_gap: 3,
+            ..Default::default()
+        })
+        .embed_tree(&tree);
+
+    // a, b and c take 3 units each, the subtrees of a and b as well as the ones of left and c
+    // are separated by 3 units
+    let x_extents = embedding
+        .iter()
+        .map(|item| (item.text.as_str(), item.x_extent, item.x_extent_children))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("a", 3, 3),
+            ("b", 3, 3),
+            ("left", 6, 9),
+            ("c", 3, 3),
+            ("root", 6, 15)
+        ],
+        x_extents
+    );
+    let x_centers = embedding
+        .iter()
+        .map(|item| item.x_center)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![1, 7, 4, 13, 7], x_centers);
+}
+
+#[test]
+fn left_alignment() {
+    let (tree, left) = create_tree();
+
+    let embedding = Embedder::new()
+        .with_options(LayoutOptions {
+            alignment: Alignment::Left,
+            ..Default::default()
+        })
+        .embed_tree(&tree);
+
+    for item in &embedding {
+        let slot = embedding.subtree_bounding_box(item.ord);
+        assert_eq!(slot.x, item.x_center - item.x_extent / 2, "{}", item.text);
+        if let Some(first_child) = embedding.children(item.ord).next() {
+            assert_eq!(slot.x, embedding.subtree_bounding_box(first_child.ord).x);
+        }
+    }
+    assert_eq!(0, embedding.bounding_box().x);
+    assert_eq!(7, embedding.bounding_box().width);
+
+    let mut incremental_embedder = IncrementalEmbedder::new().with_options(LayoutOptions {
+        alignment: Alignment::Left,
+        ..Default::default()
+    });
+    incremental_embedder.embed(&tree);
+    assert_eq!(
+        embedding[..],
+        incremental_embedder.update(&tree, &[left])[..]
+    );
+}
+
+#[test]
+fn incremental_embedder_with_gaps() {
+    let (mut tree, left) = create_tree();
+    let options = LayoutOptions {
+        sibling_gap: 2,
+        subtree_gap: 1,
+        ..Default::default()
+    };
+
+    let mut incremental_embedder = IncrementalEmbedder::new().with_options(options);
+    incremental_embedder.embed(&tree);
+    let child = tree
+        .insert(Node::new(MyNodeData("longer")), UnderNode(&left))
+        .unwrap();
+
+    assert_eq!(
+        Embedder::new().with_options(options).embed_tree(&tree)[..],
+        incremental_embedder.update(&tree, &[left, child])[..]
+    );
+}
+
+#[test]
+fn bottom_up_geometry() {
+    let (tree, _) = create_tree();
+    let embedding = Embedder::embed(&tree);
+
+    let top_down = SvgDrawer::new().geometry(&embedding);
+    let bottom_up = SvgDrawer::new()
+        .with_options(LayoutOptions {
+            orientation: Orientation::BottomUp,
+            level_gap: 4.0,
+            ..Default::default()
+        })
+        .geometry(&embedding);
+
+    // The levels are 50 units apart and in reversed order
+    let root = bottom_up.node(embedding.root().unwrap().ord).unwrap();
+    for node in bottom_up.nodes() {
+        assert_eq!(
+            root.rect.y - 50.0 * node.y_order as f64,
+            node.rect.y,
+            "{}",
+            embedding[node.ord].text
+        );
+        assert_eq!(top_down.node(node.ord).unwrap().rect.x, node.rect.x);
+    }
+    for edge in bottom_up.edges() {
+        assert!(edge.from.y < bottom_up.node(edge.parent).unwrap().rect.y + 1.0);
+        assert!(edge.to.y > bottom_up.node(edge.child).unwrap().rect.bottom());
+    }
+    assert_eq!(top_down.size().width, bottom_up.size().width);
+}
+
+#[test]
+fn layouter_with_options() {
+    let (tree, _) = create_tree();
+    let file_name = std::env::temp_dir().join("id_tree_layout_options.svg");
+
+    Layouter::new(&tree)
+        .with_options(LayoutOptions {
+            margin_x: 30.0,
+            ..Default::default()
+        })
+        .with_file_path(&file_name)
+        .write()
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    // The tree is 7 units, i.e. 70 pixels, wide
+    assert!(svg.contains("width=\"100\""));
+}
+
+#[cfg(feature = "serde_support")]
+#[test]
+fn deserialize_partial_options() {
+    let options: LayoutOptions =
+        serde_json::from_str(r#"{ "subtree_gap": 2, "orientation": "BottomUp" }"#).unwrap();
+
+    assert_eq!(
+        LayoutOptions {
+            subtree_gap: 2,
+            orientation: Orientation::BottomUp,
+            ..Default::default()
+        },
+        options
+    );
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(options, serde_json::from_str(&json).unwrap());
+}
@@ -0,0 +1,274 @@
+use id_tree::InsertBehavior::*;
+use id_tree::RemoveBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+use std::path::Path;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree() -> (Tree<MyNodeData>, NodeId) {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let child = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(2)), UnderNode(&child))
+        .unwrap();
+    (tree, child)
+}
+
+#[test]
+fn missing_file_path() {
+    let (tree, _) = create_tree();
+
+    let result = Layouter::new(&tree).write();
+
+    assert!(matches!(
+        result,
+        Err(LayouterError::MissingConfiguration(_))
+    ));
+}
+
+#[test]
+fn io_failure() {
+    let (tree, _) = create_tree();
+
+    let result = Layouter::new(&tree)
+        .with_file_path(Path::new("/non/existing/directory/test.svg"))
+        .write();
+
+    match result {
+        Err(LayouterError::Io(err)) => assert_eq!(std::io::ErrorKind::NotFound, err.kind()),
+        _ => panic!("Expected an I/O error"),
+    }
+}
+
+#[test]
+fn stale_subtree_root() {
+    let (mut tree, child) = create_tree();
+    tree.remove_node(child.clone(), DropChildren).unwrap();
+
+    let result = Layouter::new(&tree)
+        .with_file_path(Path::new("never_written.svg"))
+        .with_root(child)
+        .write();
+
+    assert!(matches!(
+        result,
+        Err(LayouterError::InvalidTree(NodeIdError::NodeIdNoLongerValid))
+    ));
+    assert!(!Path::new("never_written.svg").exists());
+}
+
+#[test]
+fn node_limit() {
+    let (tree, child) = create_tree();
+    let file_name = std::env::temp_dir().join("id_tree_layout_node_limit.svg");
+
+    let result = Layouter::new(&tree)
+        .with_file_path(&file_name)
+        .with_node_limit(2)
+        .write();
+
+    match result {
+        Err(LayouterError::ResourceLimit {
+            resource,
+            limit,
+            actual,
+        }) => assert_eq!(("nodes", 2, 3), (resource, limit, actual)),
+        _ => panic!("Expected a resource limit error"),
+    }
+    assert!(!file_name.exists());
+
+    // The subtree is small enough
+    Layouter::new(&tree)
+        .with_file_path(&file_name)
+        .with_root(child)
+        .with_node_limit(2)
+        .write()
+        .unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+}
+
+#[derive(Debug)]
+struct UnsupportedCharacter(char);
+
+impl std::fmt::Display for UnsupportedCharacter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported character '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedCharacter {}
+
+struct AsciiOnlyDrawer;
+
+impl Drawer for AsciiOnlyDrawer {
+    fn draw(&self, _file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        match embedding
+            .iter()
+            .flat_map(|item| item.text.chars())
+            .find(|c| !c.is_ascii())
+        {
+            Some(c) => Err(drawer::DrawerError::other(UnsupportedCharacter(c))),
+            None => Ok(()),
+        }
+    }
+}
+
+struct TextData(&'static str);
+
+impl Visualize for TextData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn drawer_specific_failure() {
+    let mut tree: Tree<TextData> = TreeBuilder::new().build();
+    tree.insert(Node::new(TextData("Grüße")), AsRoot).unwrap();
+
+    let result = Layouter::new(&tree)
+        .with_drawer(&AsciiOnlyDrawer)
+        .with_file_path(Path::new("never_written.svg"))
+        .write();
+
+    match result {
+        Err(LayouterError::Drawer(err)) => {
+            let err = err.downcast_ref::<UnsupportedCharacter>().unwrap();
+            assert_eq!('ü', err.0);
+        }
+        _ => panic!("Expected a drawer error"),
+    }
+}
+
+#[test]
+fn drawer_selected_by_extension() {
+    let (tree, _) = create_tree();
+    let file_name = std::env::temp_dir().join("id_tree_layout_extension.SVG");
+
+    Layouter::new(&tree)
+        .with_file_path(&file_name)
+        .write()
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    assert!(svg.contains("<svg"));
+
+    let mut formats = vec![
+        ("dot", "digraph tree {"),
+        ("txt", "|"),
+        ("html", "<!DOCTYPE html>"),
+    ];
+    if cfg!(feature = "serde_support") {
+        formats.push(("json", "\"items\": ["));
+    }
+    for (extension, start) in formats {
+        let file_name =
+            std::env::temp_dir().join(format!("id_tree_layout_extension.{}", extension));
+        Layouter::new(&tree)
+            .with_file_path(&file_name)
+            .write()
+            .unwrap();
+        let output = std::fs::read_to_string(&file_name).unwrap();
+        std::fs::remove_file(&file_name).unwrap();
+        assert!(output.contains(start), "Unexpected {} output", extension);
+    }
+
+    for (path, extension) in &[("never_written.png", "png"), ("never_written", "")] {
+        match Layouter::new(&tree).with_file_path(Path::new(path)).write() {
+            Err(err @ LayouterError::UnsupportedFormat(_)) => {
+                assert!(matches!(&err, LayouterError::UnsupportedFormat(e) if e == extension));
+                assert!(err.to_string().starts_with("Unsupported output format"));
+            }
+            _ => panic!("Expected an unsupported format error"),
+        }
+        assert!(!Path::new(path).exists());
+    }
+
+    // An explicit drawer is used regardless of the extension
+    Layouter::new(&tree)
+        .with_drawer(&AsciiOnlyDrawer)
+        .with_file_path(Path::new("never_written.png"))
+        .write()
+        .unwrap();
+}
+
+struct CountingData(std::rc::Rc<std::cell::Cell<usize>>);
+
+impl Visualize for CountingData {
+    fn visualize(&self) -> std::string::String {
+        self.0.set(self.0.get() + 1);
+        "counted".to_string()
+    }
+}
+
+#[test]
+fn several_outputs_in_one_pass() {
+    let visualized = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut tree: Tree<CountingData> = TreeBuilder::new().build();
+    tree.insert(Node::new(CountingData(visualized.clone())), AsRoot)
+        .unwrap();
+    let first = std::env::temp_dir().join("id_tree_layout_first.svg");
+    let second = std::env::temp_dir().join("id_tree_layout_second.svg");
+    let mut formats = vec![("dot", "label=\"counted\""), ("txt", "counted")];
+    if cfg!(feature = "serde_support") {
+        formats.push(("json", "\"text\": \"counted\""));
+    }
+    let outputs = formats
+        .iter()
+        .map(|(extension, _)| {
+            std::env::temp_dir().join(format!("id_tree_layout_outputs.{}", extension))
+        })
+        .collect::<Vec<_>>();
+    let unsupported = Path::new("never_written.png");
+    let unwritable = Path::new("/non/existing/directory/test.svg");
+
+    let mut layouter = Layouter::new(&tree)
+        .with_file_path(&first)
+        .with_output(unsupported)
+        .with_output(unwritable)
+        .with_drawer_output(&AsciiOnlyDrawer, &second);
+    for output in &outputs {
+        layouter = layouter.with_output(output);
+    }
+    let result = layouter.write_all();
+
+    // The tree was embedded once and the failures didn't prevent the other outputs
+    assert_eq!(1, visualized.get());
+    assert!(std::fs::read_to_string(&first).unwrap().contains("counted"));
+    std::fs::remove_file(&first).unwrap();
+    for ((extension, content), output) in formats.iter().zip(&outputs) {
+        let written = std::fs::read_to_string(output).unwrap();
+        std::fs::remove_file(output).unwrap();
+        assert!(written.contains(content), "Unexpected {} output", extension);
+    }
+    assert!(!second.exists());
+    match result {
+        Err(LayouterError::Outputs(failures)) => {
+            assert_eq!(2, failures.len());
+            assert_eq!(unsupported, failures[0].0);
+            assert!(matches!(failures[0].1, LayouterError::UnsupportedFormat(_)));
+            assert_eq!(unwritable, failures[1].0);
+            assert!(matches!(failures[1].1, LayouterError::Io(_)));
+        }
+        _ => panic!("Expected the failures of two outputs"),
+    }
+}
+
+#[test]
+fn write_all_without_outputs() {
+    let (tree, _) = create_tree();
+
+    assert!(matches!(
+        Layouter::new(&tree).write_all(),
+        Err(LayouterError::MissingConfiguration(_))
+    ));
+}
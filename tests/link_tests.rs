@@ -0,0 +1,81 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct Rule {
+    name: &'static str,
+    span: Option<(usize, usize)>,
+}
+
+impl Visualize for Rule {
+    fn visualize(&self) -> std::string::String {
+        self.name.to_string()
+    }
+
+    fn tooltip(&self) -> Option<String> {
+        self.span.map(|(start, end)| format!("{}..{}", start, end))
+    }
+
+    fn url(&self) -> Option<String> {
+        self.span
+            .map(|_| format!("https://example.com/grammar?rule={}&lang=en", self.name))
+    }
+}
+
+fn create_tree() -> Tree<Rule> {
+    let mut tree: Tree<Rule> = TreeBuilder::new().build();
+    let root = tree
+        .insert(
+            Node::new(Rule {
+                name: "expr",
+                span: Some((0, 5)),
+            }),
+            AsRoot,
+        )
+        .unwrap();
+    tree.insert(
+        Node::new(Rule {
+            name: "ws",
+            span: None,
+        }),
+        UnderNode(&root),
+    )
+    .unwrap();
+    tree
+}
+
+#[test]
+fn tooltips_and_urls_are_embedded() {
+    let tree = create_tree();
+    let mut incremental_embedder = IncrementalEmbedder::new();
+
+    for embedding in [&Embedder::embed(&tree), incremental_embedder.embed(&tree)] {
+        let root = embedding.root().unwrap();
+        assert_eq!(Some("0..5".to_string()), root.tooltip);
+        assert_eq!(
+            Some("https://example.com/grammar?rule=expr&lang=en".to_string()),
+            root.url
+        );
+        let leaf = &embedding[0];
+        assert_eq!((None, None), (leaf.tooltip.clone(), leaf.url.clone()));
+    }
+}
+
+#[test]
+fn tooltips_and_links_are_drawn() {
+    let embedding = Embedder::embed(&create_tree());
+    let file_name = std::env::temp_dir().join("id_tree_layout_links.svg");
+    SvgDrawer::new().draw(&file_name, &embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert!(svg.contains("<title>expr\n0..5</title>"));
+    assert!(svg.contains("<title>ws</title>"));
+    assert_eq!(1, svg.matches("<a ").count());
+    let link = svg
+        .find("<a href=\"https://example.com/grammar?rule=expr&amp;lang=en\">")
+        .unwrap();
+    let text = svg.find(">expr</text>").unwrap();
+    let link_end = svg.find("</a>").unwrap();
+    assert!(link < text && text < link_end);
+}
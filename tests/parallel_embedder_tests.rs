@@ -0,0 +1,115 @@
+#![cfg(feature = "rayon")]
+
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(u64);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn emphasize(&self) -> bool {
+        self.0.is_multiple_of(7)
+    }
+}
+
+/// Creates a tree with pseudo-random shape and labels.
+fn create_tree(size: usize) -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(size).build();
+    let mut node_ids = vec![tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap()];
+    let mut state: u64 = 4711;
+    for _ in 1..size {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let value = state >> 33;
+        // Prefer recently inserted nodes as parents to create deep subtrees
+        let window = std::cmp::min(node_ids.len(), 16) as u64;
+        let parent = node_ids[node_ids.len() - 1 - (value % window) as usize].clone();
+        node_ids.push(
+            tree.insert(Node::new(MyNodeData(value % 100_000)), UnderNode(&parent))
+                .unwrap(),
+        );
+    }
+    tree
+}
+
+#[test]
+fn identical_to_sequential_embedding() {
+    let tree = create_tree(5000);
+    let embedder = Embedder::new().with_parallel_threshold(0);
+
+    assert_eq!(
+        embedder.embed_tree(&tree)[..],
+        embedder.par_embed_tree(&tree)[..]
+    );
+}
+
+#[test]
+fn identical_with_transformations() {
+    let tree = create_tree(5000);
+    let embedder = Embedder::new()
+        .with_chain_compression(ChainCompression::Stacked)
+        .with_hidden_nodes(|data: &MyNodeData| data.0.is_multiple_of(5))
+        .with_parallel_threshold(0);
+
+    assert_eq!(
+        embedder.embed_tree(&tree)[..],
+        embedder.par_embed_tree(&tree)[..]
+    );
+}
+
+#[test]
+fn identical_with_options() {
+    let tree = create_tree(5000);
+    let embedder = Embedder::new()
+        .with_options(LayoutOptions {
+            sibling_gap: 2,
+            subtree_gap: 3,
+            alignment: layout_options::Alignment::Left,
+            ..Default::default()
+        })
+        .with_parallel_threshold(0);
+
+    assert_eq!(
+        embedder.embed_tree(&tree)[..],
+        embedder.par_embed_tree(&tree)[..]
+    );
+}
+
+#[test]
+fn identical_for_deep_chain() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let mut parent = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    for i in 1..2000 {
+        let node_id = tree
+            .insert(Node::new(MyNodeData(i)), UnderNode(&parent))
+            .unwrap();
+        tree.insert(Node::new(MyNodeData(i * 1000)), UnderNode(&parent))
+            .unwrap();
+        parent = node_id;
+    }
+    let embedder = Embedder::new().with_parallel_threshold(0);
+
+    assert_eq!(
+        embedder.embed_tree(&tree)[..],
+        embedder.par_embed_tree(&tree)[..]
+    );
+}
+
+#[test]
+fn small_and_empty_trees() {
+    let embedder = Embedder::new();
+
+    let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    assert!(embedder.par_embed_tree(&tree).is_empty());
+
+    let tree = create_tree(10);
+    assert_eq!(
+        embedder.embed_tree(&tree)[..],
+        embedder.par_embed_tree(&tree)[..]
+    );
+}
@@ -0,0 +1,162 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str, f64);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+///
+/// Creates the call tree
+/// main(1) -> [parse(2) -> [lex(3)], run(4)]
+///
+fn create_tree() -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let main = tree
+        .insert(Node::new(MyNodeData("main", 1.0)), AsRoot)
+        .unwrap();
+    let parse = tree
+        .insert(Node::new(MyNodeData("parse", 2.0)), UnderNode(&main))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("lex", 3.0)), UnderNode(&parse))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("run", 4.0)), UnderNode(&main))
+        .unwrap();
+    tree
+}
+
+#[test]
+fn empty_tree() {
+    let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+
+    let partition = Partitioner::new().partition(&tree);
+
+    assert!(partition.is_empty());
+    assert_eq!(0, partition.depth());
+}
+
+#[test]
+fn leaf_count_weights() {
+    let tree = create_tree();
+
+    let partition = Partitioner::new().partition(&tree);
+
+    assert_eq!(3, partition.depth());
+    let texts = partition
+        .iter()
+        .map(|item| item.text.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(vec!["lex", "parse", "run", "main"], texts);
+
+    let main = &partition[3];
+    assert_eq!(
+        (0, 0.0, 1.0, 2.0),
+        (main.depth, main.start, main.extent, main.weight)
+    );
+    let parse = &partition[1];
+    assert_eq!((1, 0.0, 0.5), (parse.depth, parse.start, parse.extent));
+    let lex = &partition[0];
+    assert_eq!((2, 0.0, 0.5), (lex.depth, lex.start, lex.extent));
+    let run = &partition[2];
+    assert_eq!((1, 0.5, 0.5), (run.depth, run.start, run.extent));
+    assert_eq!(Some(3), run.parent);
+}
+
+#[test]
+fn own_weights() {
+    let tree = create_tree();
+
+    let partition = Partitioner::new()
+        .with_weights(|d: &MyNodeData| d.1)
+        .partition(&tree);
+
+    // main: 1 + (2 + 3) + 4
+    assert_eq!(10.0, partition[3].weight);
+    assert_eq!(5.0, partition[1].weight);
+    assert_eq!(0.5, partition[1].extent);
+    // The own weight of parse remains as a gap behind lex
+    assert_eq!(0.3, partition[0].extent);
+    assert_eq!(0.5, partition[2].start);
+    assert_eq!(0.4, partition[2].extent);
+}
+
+#[test]
+fn zero_weights() {
+    let tree = create_tree();
+
+    let partition = Partitioner::new()
+        .with_weights(|_: &MyNodeData| 0.0)
+        .partition(&tree);
+
+    assert_eq!(1.0, partition[3].extent);
+    assert!(partition[..3].iter().all(|item| item.extent == 0.0));
+}
+
+#[test]
+fn draw_icicle_and_sunburst() {
+    let tree = create_tree();
+    let partition = Partitioner::new().partition(&tree);
+    let drawer = SvgDrawer::new();
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_icicle.svg");
+    drawer.draw_icicle(&file_name, &partition, 400.0).unwrap();
+    let icicle = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert_eq!(4, icicle.matches("<clipPath").count());
+    assert_eq!(4, icicle.matches("</clipPath>").count());
+    assert!(icicle.contains("clip-path=\"url(#clip0)\""));
+    assert!(icicle.contains("width=\"420\""));
+    assert!(icicle.contains("height=\"95\""));
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_sunburst.svg");
+    drawer.draw_sunburst(&file_name, &partition, 150.0).unwrap();
+    let sunburst = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert_eq!(4, sunburst.matches("<clipPath").count());
+    assert_eq!(4, sunburst.matches("</clipPath>").count());
+    assert_eq!(8, sunburst.matches("<path").count());
+    assert!(sunburst.contains("width=\"320\""));
+}
+
+struct FileData(&'static str, Option<f64>);
+
+impl Visualize for FileData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn weight(&self) -> Option<f64> {
+        self.1
+    }
+}
+
+#[test]
+fn weights_from_visualize() {
+    let mut tree: Tree<FileData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(FileData("/", None)), AsRoot).unwrap();
+    tree.insert(Node::new(FileData("a", Some(3.0))), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(FileData("b", None)), UnderNode(&root))
+        .unwrap();
+
+    let partition = Partitioner::new().partition(&tree);
+
+    // The leaf without a weight counts 1
+    assert_eq!(4.0, partition[2].weight);
+    assert_eq!(0.75, partition[0].extent);
+
+    let treemap = Treemapper::new().layout(
+        &tree,
+        id_tree_layout::geometry::Size {
+            width: 100.0,
+            height: 100.0,
+        },
+    );
+    assert_eq!(4.0, treemap[2].weight);
+}
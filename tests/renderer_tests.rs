@@ -0,0 +1,124 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct MyNodeData(usize);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+/// Creates a chain of the given number of nodes
+fn create_tree(size: usize) -> (Tree<MyNodeData>, NodeId) {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let mut parent = root.clone();
+    for i in 1..size {
+        parent = tree
+            .insert(Node::new(MyNodeData(i)), UnderNode(&parent))
+            .unwrap();
+    }
+    (tree, root)
+}
+
+/// Counts the drawn items
+#[derive(Default)]
+struct CountingDrawer(Arc<AtomicUsize>);
+
+impl Drawer for CountingDrawer {
+    fn draw(&self, _file_name: &Path, embedding: &Embedding) -> drawer::Result {
+        self.0.fetch_add(embedding.len(), Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[test]
+fn renderer_is_send_and_sync() {
+    fn assert_send_sync<R: Send + Sync>() {}
+    assert_send_sync::<Renderer>();
+}
+
+#[test]
+fn render_many_trees_on_many_threads() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let renderer = Renderer::new().with_drawer(CountingDrawer(counter.clone()));
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let renderer = &renderer;
+            scope.spawn(move || {
+                for size in 1..=25 {
+                    let (tree, _) = create_tree(size);
+                    renderer
+                        .render(&tree, format!("never_written_{}.svg", thread))
+                        .unwrap();
+                }
+            });
+        }
+    });
+
+    // Each thread draws 1 + 2 + ... + 25 items
+    assert_eq!(4 * 325, counter.load(Ordering::SeqCst));
+}
+
+#[test]
+fn render_same_as_layouter() {
+    let (tree, root) = create_tree(3);
+    let options = LayoutOptions {
+        level_gap: 1.0,
+        ..Default::default()
+    };
+    let layouter_file = std::env::temp_dir().join("id_tree_layout_layouter.svg");
+    let renderer_file = std::env::temp_dir().join("id_tree_layout_renderer.svg");
+
+    Layouter::new(&tree)
+        .with_options(options)
+        .with_root(tree.children_ids(&root).unwrap().next().unwrap().clone())
+        .with_file_path(&layouter_file)
+        .write()
+        .unwrap();
+    Renderer::new()
+        .with_options(options)
+        .render_subtree(
+            &tree,
+            tree.children_ids(&root).unwrap().next().unwrap(),
+            &renderer_file,
+        )
+        .unwrap();
+
+    let layouter_svg = std::fs::read_to_string(&layouter_file).unwrap();
+    let renderer_svg = std::fs::read_to_string(&renderer_file).unwrap();
+    std::fs::remove_file(&layouter_file).unwrap();
+    std::fs::remove_file(&renderer_file).unwrap();
+    assert_eq!(layouter_svg, renderer_svg);
+}
+
+#[test]
+fn node_limit_per_tree() {
+    let renderer = Renderer::new()
+        .with_drawer(CountingDrawer::default())
+        .with_node_limit(10);
+
+    assert!(renderer
+        .render(&create_tree(10).0, "never_written.svg")
+        .is_ok());
+    assert!(matches!(
+        renderer.render(&create_tree(11).0, "never_written.svg"),
+        Err(LayouterError::ResourceLimit { actual: 11, .. })
+    ));
+}
+
+#[test]
+fn unsupported_format() {
+    let (tree, _) = create_tree(3);
+
+    assert!(matches!(
+        Renderer::new().render(&tree, "never_written.png"),
+        Err(LayouterError::UnsupportedFormat(extension)) if extension == "png"
+    ));
+}
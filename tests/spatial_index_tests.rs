@@ -0,0 +1,119 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::geometry::{MonospaceMeasurer, Point, Rect, Spacing};
+use id_tree_layout::*;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+fn create_tree() -> Tree<MyNodeData> {
+    //      0
+    //     / \
+    //    1   2
+    //   / \
+    //  3   4
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(5).build();
+
+    let root_id: NodeId = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let child_id: NodeId = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(2)), UnderNode(&root_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(3)), UnderNode(&child_id))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData(4)), UnderNode(&child_id))
+        .unwrap();
+    tree
+}
+
+#[test]
+fn logical_hit_testing() {
+    let tree = create_tree();
+    let embedding = Embedder::embed(&tree);
+    let index = embedding.spatial_index();
+
+    for e in &embedding {
+        let point = Point {
+            x: e.x_center as f64,
+            y: e.y_order as f64,
+        };
+        assert_eq!(Some(e.ord), index.node_at(point));
+    }
+    assert_eq!(Some(3), index.node_at(Point { x: 5.9, y: 1.2 }));
+    assert_eq!(None, index.node_at(Point { x: 6.5, y: 1.0 }));
+    assert_eq!(None, index.node_at(Point { x: 5.0, y: 2.0 }));
+
+    let all = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 6.0,
+        height: 2.0,
+    };
+    assert_eq!(vec![0, 1, 2, 3, 4], index.nodes_in(&all));
+    let lower_left = Rect {
+        x: 0.0,
+        y: 1.0,
+        width: 2.5,
+        height: 1.0,
+    };
+    assert_eq!(vec![0, 1, 2], index.nodes_in(&lower_left));
+}
+
+#[test]
+fn svg_hit_testing() {
+    let tree = create_tree();
+    let embedding = Embedder::embed(&tree);
+    let geometry = SvgDrawer::new().geometry(&embedding);
+    let index = geometry.spatial_index();
+
+    for node in geometry.nodes() {
+        assert_eq!(Some(node.ord), index.node_at(node.rect.center()));
+    }
+    // Between the levels
+    assert_eq!(None, index.node_at(Point { x: 40.0, y: 30.0 }));
+
+    let upper_half = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: geometry.size().width,
+        height: geometry.size().height / 2.0,
+    };
+    assert_eq!(vec![2, 3, 4], index.nodes_in(&upper_half));
+}
+
+#[test]
+fn overlapping_levels() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root_id = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    tree.insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    let embedding = Embedder::embed(&tree);
+
+    // Logical areas of consecutive levels share their borders
+    let border = Point {
+        x: embedding[0].x_center as f64,
+        y: 0.5,
+    };
+    assert_eq!(Some(0), embedding.spatial_index().node_at(border));
+
+    // Levels closer than the line height overlap
+    let spacing = Spacing {
+        level_height: 5.0,
+        ..Spacing::default()
+    };
+    let geometry = Geometry::new(&embedding, &MonospaceMeasurer::default(), &spacing);
+    let root = geometry.node(1).unwrap().rect;
+    let child = geometry.node(0).unwrap().rect;
+    let point = Point {
+        x: child.center().x,
+        y: (child.y + root.bottom()) / 2.0,
+    };
+    assert!(root.contains(point) && child.contains(point));
+    assert_eq!(Some(0), geometry.spatial_index().node_at(point));
+}
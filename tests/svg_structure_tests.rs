@@ -0,0 +1,109 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str, bool);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn emphasize(&self) -> bool {
+        self.1
+    }
+}
+
+fn create_tree() -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData("root", true)), AsRoot)
+        .unwrap();
+    let a = tree
+        .insert(Node::new(MyNodeData("a", false)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("a1", false)), UnderNode(&a))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("b", false)), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+fn draw(drawer: &SvgDrawer, embedding: &Embedding, name: &str) -> String {
+    let file_name = std::env::temp_dir().join(name);
+    drawer.draw(&file_name, embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    svg
+}
+
+#[test]
+fn groups_with_ids_and_classes() {
+    let embedding = Embedder::embed(&create_tree());
+    let svg = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_groups.svg");
+
+    // Post-order: a1 = 0, a = 1, b = 2, root = 3
+    assert!(svg.contains("<g id=\"node-3\" class=\"node depth-0 emphasized\""));
+    assert!(svg.contains("<g id=\"node-1\" class=\"node depth-1\""));
+    assert!(svg.contains("<g id=\"node-0\" class=\"node depth-2 leaf\""));
+    assert!(svg.contains("<g id=\"node-2\" class=\"node depth-1 leaf\""));
+    assert_eq!(3, svg.matches("class=\"edge\"").count());
+    assert!(!svg.contains(" style="));
+
+    // The groups are nested and written in pre-order
+    let root = svg.find("id=\"node-3\"").unwrap();
+    let a = svg.find("id=\"node-1\"").unwrap();
+    let a1 = svg.find("id=\"node-0\"").unwrap();
+    let b = svg.find("id=\"node-2\"").unwrap();
+    assert!(root < a && a < a1 && a1 < b);
+    // a1's group, the group of a's children and a's group end before b
+    let between_a1_and_b = &svg[a1..b];
+    assert_eq!(3, between_a1_and_b.matches("</g>").count());
+    assert_eq!(svg.matches("<g ").count(), svg.matches("</g>").count());
+}
+
+#[test]
+fn custom_node_ids() {
+    let embedding = Embedder::embed(&create_tree());
+    let drawer = SvgDrawer::new().with_node_ids(|item| format!("n<{}>", item.text));
+    let svg = draw(&drawer, &embedding, "id_tree_layout_node_ids.svg");
+
+    assert!(svg.contains("id=\"n&lt;root&gt;\""));
+    assert!(svg.contains("id=\"n&lt;a1&gt;\""));
+    assert!(!svg.contains("id=\"node-"));
+}
+
+#[test]
+fn embedded_style_sheet() {
+    let embedding = Embedder::embed(&create_tree());
+    let plain = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_plain.svg");
+    assert!(!plain.contains("<style"));
+
+    let drawer = SvgDrawer::new()
+        .with_dark_theme(theme::Theme::dark())
+        .with_style_sheet(".leaf > text { fill: darkgreen }");
+    let svg = draw(&drawer, &embedding, "id_tree_layout_style_sheet.svg");
+
+    assert_eq!(1, svg.matches("<style").count());
+    let dark = svg.find("prefers-color-scheme").unwrap();
+    let user = svg.find(".leaf &gt; text { fill: darkgreen }").unwrap();
+    assert!(dark < user);
+}
+
+#[test]
+fn deep_chain() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let mut parent = tree
+        .insert(Node::new(MyNodeData("n", false)), AsRoot)
+        .unwrap();
+    for _ in 0..2000 {
+        parent = tree
+            .insert(Node::new(MyNodeData("n", false)), UnderNode(&parent))
+            .unwrap();
+    }
+    let embedding = Embedder::embed(&tree);
+    let svg = draw(&SvgDrawer::new(), &embedding, "id_tree_layout_deep.svg");
+
+    // The group of each node and the groups of the children of all but the last node
+    assert_eq!(4001, svg.matches("</g>").count());
+}
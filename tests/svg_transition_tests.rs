@@ -0,0 +1,76 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::*;
+
+struct MyNodeData(i32);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn transition_between_tree_states() {
+    //      0            0
+    //     / \          / \
+    //    1   2   =>   1   3
+    //   /
+    //  4
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(5).build();
+    let root_id = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let n1 = tree
+        .insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    let n2 = tree
+        .insert(Node::new(MyNodeData(2)), UnderNode(&root_id))
+        .unwrap();
+    let n4 = tree
+        .insert(Node::new(MyNodeData(4)), UnderNode(&n1))
+        .unwrap();
+    let before = Embedder::embed(&tree);
+
+    tree.remove_node(n2, RemoveBehavior::DropChildren).unwrap();
+    tree.remove_node(n4, RemoveBehavior::DropChildren).unwrap();
+    tree.insert(Node::new(MyNodeData(3)), UnderNode(&root_id))
+        .unwrap();
+    let after = Embedder::embed(&tree);
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_transition.svg");
+    // The removed nodes' ids are reused by id_tree, thus we match the nodes by their texts
+    SvgTransition::new()
+        .with_duration(0.5)
+        .draw_with_key(&file_name, &before, &after, |item| Some(item.text.clone()))
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    let _ = std::fs::remove_file(file_name);
+
+    // Nodes 0 and 1 move, nodes 2 and 4 and their edges fade out, node 3 and its edge fade in
+    assert_eq!(2, svg.matches("<animateTransform").count());
+    assert_eq!(4, svg.matches(r#"from="1" to="0""#).count());
+    assert_eq!(2, svg.matches(r#"from="0" to="1""#).count());
+    // The edge from 0 to 1 moves
+    assert_eq!(1, svg.matches(r#"attributeName="x1""#).count());
+    assert!(svg.contains(r#"dur="0.5s""#));
+}
+
+#[test]
+fn transition_matched_by_node_ids() {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().with_node_capacity(3).build();
+    let root_id = tree.insert(Node::new(MyNodeData(0)), AsRoot).unwrap();
+    let before = Embedder::embed(&tree);
+    tree.insert(Node::new(MyNodeData(1)), UnderNode(&root_id))
+        .unwrap();
+    let after = Embedder::embed(&tree);
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_transition_ids.svg");
+    SvgTransition::new()
+        .draw(&file_name, &before, &after)
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    let _ = std::fs::remove_file(file_name);
+
+    assert_eq!(1, svg.matches("<animateTransform").count());
+    assert_eq!(2, svg.matches(r#"from="0" to="1""#).count());
+    assert!(svg.contains(r#"dur="1s""#));
+}
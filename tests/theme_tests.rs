@@ -0,0 +1,179 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::theme::Theme;
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str, f64);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+
+    fn weight(&self) -> Option<f64> {
+        Some(self.1)
+    }
+}
+
+fn create_tree() -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData("root", 0.0)), AsRoot)
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("a", 1.0)), UnderNode(&root))
+        .unwrap();
+    tree.insert(Node::new(MyNodeData("b", 2.0)), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+fn draw(drawer: &SvgDrawer, embedding: &Embedding, name: &str) -> String {
+    let file_name = std::env::temp_dir().join(name);
+    drawer.draw(&file_name, embedding).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    svg
+}
+
+#[test]
+fn named_themes() {
+    assert_eq!(Some(Theme::light()), Theme::named("light"));
+    assert_eq!(Some(Theme::high_contrast()), Theme::named("high-contrast"));
+    assert_eq!(Theme::named("grayscale"), Theme::named("print"));
+    assert_eq!(None, Theme::named("solarized"));
+    assert_eq!(Theme::light(), Theme::default());
+}
+
+#[test]
+fn dark_theme() {
+    let embedding = Embedder::embed(&create_tree());
+
+    let svg = draw(
+        &SvgDrawer::new().with_theme(Theme::dark()),
+        &embedding,
+        "id_tree_layout_dark.svg",
+    );
+
+    assert!(svg.contains("fill=\"#d4d4d4\""));
+    assert!(svg.contains("fill=\"#1e1e1e\""));
+    assert_eq!(2, svg.matches("stroke=\"#a0a0a0\"").count());
+    assert!(!svg.contains("white"));
+    assert!(!svg.contains("<style"));
+}
+
+#[test]
+fn adaptive_color_scheme() {
+    let embedding = Embedder::embed(&create_tree());
+
+    let svg = draw(
+        &SvgDrawer::new().with_dark_theme(Theme::dark()),
+        &embedding,
+        "id_tree_layout_adaptive.svg",
+    );
+
+    // The light theme is the default, the dark one is applied by the style sheet
+    assert!(svg.contains("fill=\"white\""));
+    assert!(svg.contains("@media (prefers-color-scheme: dark)"));
+    assert!(svg.contains("svg.id-tree-layout rect.background { fill: #1e1e1e }"));
+    assert!(svg.contains("svg.id-tree-layout line { stroke: #a0a0a0 }"));
+    assert!(svg.contains(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" class=\"id-tree-layout\""
+    ));
+    assert!(svg.contains("<rect class=\"background\""));
+}
+
+#[test]
+fn themed_space_filling_diagrams() {
+    let tree = create_tree();
+    let theme = Theme {
+        palette: vec!["#111111".to_string(), "#222222".to_string()],
+        ..Theme::grayscale()
+    };
+    let file_name = std::env::temp_dir().join("id_tree_layout_themed_icicle.svg");
+
+    SvgDrawer::new()
+        .with_theme(theme)
+        .draw_icicle(&file_name, &Partitioner::new().partition(&tree), 300.0)
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert!(svg.contains("fill=\"#eeeeee\""));
+    assert!(svg.contains("fill=\"#111111\""));
+    assert!(svg.contains("fill=\"#222222\""));
+}
+
+#[test]
+fn colors_are_escaped() {
+    let tree = create_tree();
+    let injected = "red\" onload=\"alert(1)".to_string();
+    let theme = Theme {
+        background: injected.clone(),
+        text: injected.clone(),
+        line: injected.clone(),
+        border: injected.clone(),
+        root_fill: injected.clone(),
+        palette: vec![injected.clone()],
+        ..Theme::light()
+    };
+    let drawer = SvgDrawer::new().with_theme(theme);
+
+    let svg = draw(
+        &drawer,
+        &Embedder::embed(&tree),
+        "id_tree_layout_escaped.svg",
+    );
+    assert!(!svg.contains("onload=\""));
+    assert!(svg.contains("fill=\"red&quot; onload=&quot;alert(1)\""));
+    assert!(svg.contains("stroke=\"red&quot; onload=&quot;alert(1)\""));
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_escaped_treemap.svg");
+    drawer
+        .draw_treemap(
+            &file_name,
+            &Treemapper::new().layout(
+                &tree,
+                geometry::Size {
+                    width: 200.0,
+                    height: 100.0,
+                },
+            ),
+        )
+        .unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+    assert!(!svg.contains("onload=\""));
+}
+
+#[test]
+fn dark_colors_dont_break_out_of_the_style_sheet() {
+    let dark = Theme {
+        line: "red } svg { display: none".to_string(),
+        ..Theme::dark()
+    };
+
+    let svg = draw(
+        &SvgDrawer::new().with_dark_theme(dark),
+        &Embedder::embed(&create_tree()),
+        "id_tree_layout_injected_style_sheet.svg",
+    );
+    assert!(!svg.contains("display: none"));
+    assert!(!svg.contains("svg.id-tree-layout line"));
+    assert!(svg.contains("svg.id-tree-layout rect.background { fill: #1e1e1e }"));
+}
+
+#[cfg(feature = "serde_support")]
+#[test]
+fn custom_theme_from_json() {
+    let theme: Theme =
+        serde_json::from_str(r##"{ "background": "ivory", "palette": ["#aaaaaa"] }"##).unwrap();
+
+    assert_eq!(
+        Theme {
+            background: "ivory".to_string(),
+            palette: vec!["#aaaaaa".to_string()],
+            ..Theme::light()
+        },
+        theme
+    );
+}
@@ -0,0 +1,177 @@
+use id_tree::InsertBehavior::*;
+use id_tree::*;
+use id_tree_layout::geometry::{Rect, Size};
+use id_tree_layout::*;
+
+struct MyNodeData(&'static str, f64);
+
+impl Visualize for MyNodeData {
+    fn visualize(&self) -> std::string::String {
+        self.0.to_string()
+    }
+}
+
+///
+/// Creates the directory tree
+/// root -> [src -> [a(6), b(6), c(4), d(3), e(2), f(2), g(1)], doc(24)]
+///
+fn create_tree() -> Tree<MyNodeData> {
+    let mut tree: Tree<MyNodeData> = TreeBuilder::new().build();
+    let root = tree
+        .insert(Node::new(MyNodeData("root", 0.0)), AsRoot)
+        .unwrap();
+    let src = tree
+        .insert(Node::new(MyNodeData("src", 0.0)), UnderNode(&root))
+        .unwrap();
+    for (name, size) in &[
+        ("a", 6.0),
+        ("b", 6.0),
+        ("c", 4.0),
+        ("d", 3.0),
+        ("e", 2.0),
+        ("f", 2.0),
+        ("g", 1.0),
+    ] {
+        tree.insert(Node::new(MyNodeData(name, *size)), UnderNode(&src))
+            .unwrap();
+    }
+    tree.insert(Node::new(MyNodeData("doc", 24.0)), UnderNode(&root))
+        .unwrap();
+    tree
+}
+
+fn area(rect: &Rect) -> f64 {
+    rect.width * rect.height
+}
+
+fn contains(outer: &Rect, inner: &Rect) -> bool {
+    const EPSILON: f64 = 1e-9;
+    inner.x >= outer.x - EPSILON
+        && inner.y >= outer.y - EPSILON
+        && inner.right() <= outer.right() + EPSILON
+        && inner.bottom() <= outer.bottom() + EPSILON
+}
+
+#[test]
+fn empty_tree() {
+    let tree: Tree<MyNodeData> = TreeBuilder::new().build();
+
+    let treemap = Treemapper::new().layout(
+        &tree,
+        Size {
+            width: 100.0,
+            height: 100.0,
+        },
+    );
+
+    assert!(treemap.is_empty());
+}
+
+#[test]
+fn areas_are_proportional_to_weights() {
+    let tree = create_tree();
+
+    let treemap = Treemapper::new()
+        .with_weights(|d: &MyNodeData| d.1)
+        .with_padding(0.0)
+        .with_header_height(0.0)
+        .layout(
+            &tree,
+            Size {
+                width: 600.0,
+                height: 400.0,
+            },
+        );
+
+    let root = treemap.last().unwrap();
+    assert_eq!(48.0, root.weight);
+    let scale = area(&root.rect) / root.weight;
+    for item in &treemap {
+        assert!((area(&item.rect) - item.weight * scale).abs() < 1e-6);
+        if let Some(parent) = item.parent {
+            assert!(contains(&treemap[parent].rect, &item.rect));
+        }
+    }
+}
+
+#[test]
+fn squarified_aspect_ratios() {
+    let tree = create_tree();
+
+    let treemap = Treemapper::new()
+        .with_weights(|d: &MyNodeData| d.1)
+        .with_padding(0.0)
+        .with_header_height(0.0)
+        .layout(
+            &tree,
+            Size {
+                width: 600.0,
+                height: 400.0,
+            },
+        );
+
+    for item in &treemap {
+        let ratio = f64::max(
+            item.rect.width / item.rect.height,
+            item.rect.height / item.rect.width,
+        );
+        assert!(ratio < 3.0, "{} has aspect ratio {}", item.text, ratio);
+    }
+}
+
+#[test]
+fn headers_and_padding() {
+    let tree = create_tree();
+
+    let treemap = Treemapper::new()
+        .with_padding(5.0)
+        .with_header_height(20.0)
+        .layout(
+            &tree,
+            Size {
+                width: 600.0,
+                height: 400.0,
+            },
+        );
+
+    for item in &treemap {
+        if item.parent.is_none() || treemap.iter().any(|i| i.parent == Some(item.ord)) {
+            assert_eq!(20.0, item.header.height);
+            assert_eq!(item.rect.y, item.header.y);
+        } else {
+            assert_eq!(0.0, item.header.height);
+        }
+        if let Some(parent) = item.parent {
+            let parent = &treemap[parent];
+            let content = Rect {
+                x: parent.rect.x + 5.0,
+                y: parent.header.bottom(),
+                width: parent.rect.width - 10.0,
+                height: parent.rect.height - 25.0,
+            };
+            assert!(contains(&content, &item.rect));
+            assert!(item.depth == parent.depth + 1);
+        }
+    }
+}
+
+#[test]
+fn draw_treemap() {
+    let tree = create_tree();
+    let treemap = Treemapper::new().with_weights(|d: &MyNodeData| d.1).layout(
+        &tree,
+        Size {
+            width: 600.0,
+            height: 400.0,
+        },
+    );
+
+    let file_name = std::env::temp_dir().join("id_tree_layout_treemap.svg");
+    SvgDrawer::new().draw_treemap(&file_name, &treemap).unwrap();
+    let svg = std::fs::read_to_string(&file_name).unwrap();
+    std::fs::remove_file(&file_name).unwrap();
+
+    assert_eq!(10, svg.matches("</clipPath>").count());
+    assert_eq!(10, svg.matches("</text>").count());
+    assert!(svg.contains("width=\"620\""));
+}